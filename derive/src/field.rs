@@ -0,0 +1,217 @@
+//! Field element literals checked against field modulus.
+
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{Error, Ident, Lit, Token};
+
+/// Field wrappers with their moduli as little-endian limbs.
+const FIELDS: &[(&str, &str, &[u64])] = &[
+    (
+        "Bls12381Base",
+        "Bls12381Base",
+        &[
+            0xb9feffffffffaaab,
+            0x1eabfffeb153ffff,
+            0x6730d2a0f6b0f624,
+            0x64774b84f38512bf,
+            0x4b1ba7b6434bacd7,
+            0x1a0111ea397fe69a,
+        ],
+    ),
+    (
+        "Bls12381Scalar",
+        "Bls12381Scalar",
+        &[
+            0xffffffff00000001,
+            0x53bda402fffe5bfe,
+            0x3339d80809a1d805,
+            0x73eda753299d7d48,
+        ],
+    ),
+    (
+        "Curve25519Base",
+        "Curve25519Base",
+        &[
+            0xffffffffffffffed,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0x7fffffffffffffff,
+        ],
+    ),
+    (
+        "Curve25519Scalar",
+        "Curve25519Scalar",
+        &[
+            0x5812631a5cf5d3ed,
+            0x14def9dea2f79cd6,
+            0x0000000000000000,
+            0x1000000000000000,
+        ],
+    ),
+    (
+        "PallasBase",
+        "PallasBase",
+        &[
+            0x992d30ed00000001,
+            0x224698fc094cf91b,
+            0x0000000000000000,
+            0x4000000000000000,
+        ],
+    ),
+    (
+        "PallasScalar",
+        "PallasScalar",
+        &[
+            0x8c46eb2100000001,
+            0x224698fc0994a8dd,
+            0x0000000000000000,
+            0x4000000000000000,
+        ],
+    ),
+    // Aliases, which cannot be used as tuple struct constructors.
+    (
+        "VestaBase",
+        "PallasScalar",
+        &[
+            0x8c46eb2100000001,
+            0x224698fc0994a8dd,
+            0x0000000000000000,
+            0x4000000000000000,
+        ],
+    ),
+    (
+        "VestaScalar",
+        "PallasBase",
+        &[
+            0x992d30ed00000001,
+            0x224698fc094cf91b,
+            0x0000000000000000,
+            0x4000000000000000,
+        ],
+    ),
+];
+
+/// Input of `field!`: wrapper type and the value.
+pub(crate) struct FieldLiteral {
+    ty: Ident,
+    value: Lit,
+}
+
+impl Parse for FieldLiteral {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ty = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let value = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self { ty, value })
+    }
+}
+
+/// Parses decimal or `0x`-prefixed hexadecimal unsigned integer into little-endian limbs,
+/// returning `None` if it does not fit.
+fn parse_le_limbs(s: &str, len: usize) -> Result<Option<Vec<u64>>, ()> {
+    let (digits, radix) = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => (hex, 16),
+        None => (s, 10),
+    };
+    if digits.is_empty() {
+        return Err(());
+    }
+
+    let mut limbs = vec![0u64; len];
+    for c in digits.chars() {
+        let mut carry = c.to_digit(radix).ok_or(())? as u128;
+        for limb in limbs.iter_mut() {
+            let acc = *limb as u128 * radix as u128 + carry;
+            *limb = acc as u64;
+            carry = acc >> 64;
+        }
+        if carry != 0 {
+            return Ok(None);
+        }
+    }
+    Ok(Some(limbs))
+}
+
+/// Returns decimal representation of little-endian limbs.
+fn to_decimal(limbs: &[u64]) -> String {
+    let mut limbs = limbs.to_vec();
+    let mut digits = Vec::new();
+    loop {
+        let mut rem = 0u128;
+        for limb in limbs.iter_mut().rev() {
+            let acc = (rem << 64) | *limb as u128;
+            *limb = (acc / 10) as u64;
+            rem = acc % 10;
+        }
+        digits.push(b'0' + rem as u8);
+        if limbs.iter().all(|&limb| limb == 0) {
+            break;
+        }
+    }
+    digits.reverse();
+    String::from_utf8(digits).unwrap()
+}
+
+pub(crate) fn expand_field(input: FieldLiteral) -> syn::Result<TokenStream> {
+    let name = input.ty.to_string();
+    let Some(&(_, wrapper, modulus)) = FIELDS.iter().find(|(alias, _, _)| *alias == name) else {
+        return Err(Error::new_spanned(
+            &input.ty,
+            "expected field wrapper type, e.g. `PallasBase`",
+        ));
+    };
+
+    let text = match &input.value {
+        Lit::Str(lit) => lit.value(),
+        Lit::Int(lit) if lit.suffix().is_empty() => lit.base10_digits().to_owned(),
+        _ => {
+            return Err(Error::new_spanned(
+                &input.value,
+                "expected decimal or `0x`-prefixed hexadecimal string or unsuffixed integer",
+            ))
+        }
+    };
+    let limbs = parse_le_limbs(&text, modulus.len())
+        .map_err(|()| Error::new_spanned(&input.value, "invalid field element literal"))?
+        .filter(|limbs| limbs.iter().rev().lt(modulus.iter().rev()))
+        .ok_or_else(|| {
+            let msg = format!("value is not less than the {} modulus", name);
+            Error::new_spanned(&input.value, msg)
+        })?;
+
+    let wrapper = Ident::new(wrapper, input.ty.span());
+    let literal: Literal = format!("{}g", to_decimal(&limbs)).parse().unwrap();
+    let limbs = limbs.iter().map(|&limb| Literal::u64_unsuffixed(limb));
+    Ok(quote! {
+        ::zkllvm_rslang_types::__field_literal!(#wrapper, #literal, [#(#limbs),*])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use zkllvm_rslang_types::{
+        Bls12381Base, Bls12381Scalar, Curve25519Base, Curve25519Scalar, PallasBase, PallasScalar,
+        VestaBase, VestaScalar, ZkField,
+    };
+
+    use super::FIELDS;
+
+    fn modulus(name: &str) -> &'static [u64] {
+        FIELDS.iter().find(|(alias, _, _)| *alias == name).unwrap().2
+    }
+
+    #[test]
+    fn moduli_match_crate() {
+        assert_eq!(FIELDS.len(), 8);
+        assert_eq!(modulus("Bls12381Base"), <Bls12381Base as ZkField>::MODULUS);
+        assert_eq!(modulus("Bls12381Scalar"), <Bls12381Scalar as ZkField>::MODULUS);
+        assert_eq!(modulus("Curve25519Base"), <Curve25519Base as ZkField>::MODULUS);
+        assert_eq!(modulus("Curve25519Scalar"), <Curve25519Scalar as ZkField>::MODULUS);
+        assert_eq!(modulus("PallasBase"), <PallasBase as ZkField>::MODULUS);
+        assert_eq!(modulus("PallasScalar"), <PallasScalar as ZkField>::MODULUS);
+        assert_eq!(modulus("VestaBase"), <VestaBase as ZkField>::MODULUS);
+        assert_eq!(modulus("VestaScalar"), <VestaScalar as ZkField>::MODULUS);
+    }
+}
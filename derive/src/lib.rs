@@ -0,0 +1,451 @@
+//! Procedural macros for `zkllvm-rslang-types`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse_macro_input, parse_quote, Data, DeriveInput, Error, Fields, GenericArgument, Index, Path,
+    PathArguments, PathSegment, QSelf, ReturnType, Type, TypeParamBound,
+};
+
+mod field;
+
+/// Derives `CircuitInput` for a struct.
+///
+/// The struct is flattened into assigner inputs: its fields are written in declaration order,
+/// each of them as a single entry (field and curve wrappers, arrays and vectors)
+/// or as a sequence of entries (nested structs deriving `CircuitInput`, tuples).
+/// The order is listed in the documentation of the generated impl.
+///
+/// ```
+/// use zkllvm_rslang_types::{CircuitInput, Pallas, PallasBase};
+///
+/// #[derive(CircuitInput)]
+/// struct Input {
+///     point: Pallas,
+///     scalars: [PallasBase; 2],
+/// }
+/// ```
+///
+/// Enums and unions are not supported:
+///
+/// ```compile_fail
+/// use zkllvm_rslang_types::{CircuitInput, PallasBase};
+///
+/// #[derive(CircuitInput)]
+/// enum Input {
+///     A(PallasBase),
+///     B(PallasBase, PallasBase),
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use zkllvm_rslang_types::CircuitInput;
+///
+/// #[derive(CircuitInput)]
+/// union Input {
+///     a: u64,
+///     b: u32,
+/// }
+/// ```
+///
+/// Every field must be an input itself:
+///
+/// ```compile_fail
+/// use zkllvm_rslang_types::{CircuitInput, PallasBase};
+///
+/// #[derive(CircuitInput)]
+/// struct Input {
+///     x: PallasBase,
+///     count: u32,
+/// }
+/// ```
+#[proc_macro_derive(CircuitInput)]
+pub fn derive_circuit_input(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_circuit_input(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Creates field wrapper constant from decimal or `0x`-prefixed hexadecimal literal,
+/// given as a string or an unsuffixed integer.
+///
+/// The value is checked at compile time to be less than the field modulus,
+/// and the expansion can be used in constant expressions:
+///
+/// ```
+/// use zkllvm_rslang_types::{field, PallasBase};
+///
+/// const X: PallasBase = field!(PallasBase, "0x1f");
+/// const Y: PallasBase = field!(PallasBase, 31);
+/// assert_eq!(X, Y);
+/// ```
+///
+/// Values not less than the modulus are rejected:
+///
+/// ```compile_fail
+/// use zkllvm_rslang_types::{field, PallasBase};
+///
+/// const P: PallasBase = field!(
+///     PallasBase,
+///     "0x40000000000000000000000000000000224698fc094cf91b992d30ed00000001"
+/// );
+/// ```
+#[proc_macro]
+pub fn field(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as field::FieldLiteral);
+    field::expand_field(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_circuit_input(mut input: DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`CircuitInput` can only be derived for structs",
+        ));
+    };
+
+    let krate = quote!(::zkllvm_rslang_types);
+    let trait_path = quote!(#krate::CircuitInput);
+
+    // Members to access fields, bindings to construct the struct back and field names for docs.
+    let mut members = Vec::new();
+    let mut bindings = Vec::new();
+    let mut doc = String::from(
+        "Assigner inputs are the fields below in order, nested structs are flattened:\n",
+    );
+    for (i, field) in data.fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => ident.to_token_stream(),
+            None => Index::from(i).to_token_stream(),
+        };
+        doc.push_str(&format!(
+            "\n{}. `{}`: `{}`",
+            i + 1,
+            member,
+            type_to_string(&field.ty)
+        ));
+        members.push(member);
+        bindings.push(format_ident!("__field{}", i));
+    }
+    if data.fields.is_empty() {
+        doc.push_str("\nThere are no fields, so there are no inputs.");
+    }
+
+    let construct = match &data.fields {
+        Fields::Named(_) => quote!(Self { #(#members: #bindings),* }),
+        Fields::Unnamed(_) => quote!(Self(#(#bindings),*)),
+        Fields::Unit => quote!(Self),
+    };
+
+    // Generic structs require their field types to be inputs.
+    if !input.generics.params.is_empty() {
+        let where_clause = input.generics.make_where_clause();
+        for field in data.fields.iter() {
+            let ty = &field.ty;
+            where_clause.predicates.push(parse_quote!(#ty: #trait_path));
+        }
+    }
+
+    let ident = &input.ident;
+    let types = data.fields.iter().map(|field| &field.ty);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        #[doc = #doc]
+        impl #impl_generics #trait_path for #ident #ty_generics #where_clause {
+            fn write_inputs(&self, inputs: &mut ::std::vec::Vec<#krate::JsonValue>) {
+                #(#trait_path::write_inputs(&self.#members, inputs);)*
+            }
+
+            fn read_inputs(
+                inputs: &mut #krate::InputReader<'_>,
+            ) -> ::std::result::Result<Self, #krate::AssignerError> {
+                #(let #bindings = <#types as #trait_path>::read_inputs(inputs)?;)*
+                ::std::result::Result::Ok(#construct)
+            }
+        }
+    })
+}
+
+/// Formats type as it is usually written, e.g. `Vec<[PallasBase; 2]>`.
+fn type_to_string(ty: &Type) -> String {
+    let mut res = String::new();
+    write_type(&mut res, ty);
+    res
+}
+
+fn write_type(out: &mut String, ty: &Type) {
+    match ty {
+        Type::Array(array) => {
+            out.push('[');
+            write_type(out, &array.elem);
+            out.push_str("; ");
+            out.push_str(&array.len.to_token_stream().to_string());
+            out.push(']');
+        }
+        Type::Slice(slice) => {
+            out.push('[');
+            write_type(out, &slice.elem);
+            out.push(']');
+        }
+        Type::Reference(reference) => {
+            out.push('&');
+            if let Some(lifetime) = &reference.lifetime {
+                out.push_str(&lifetime.to_string());
+                out.push(' ');
+            }
+            if reference.mutability.is_some() {
+                out.push_str("mut ");
+            }
+            write_type(out, &reference.elem);
+        }
+        Type::Ptr(ptr) => {
+            out.push_str(if ptr.mutability.is_some() {
+                "*mut "
+            } else {
+                "*const "
+            });
+            write_type(out, &ptr.elem);
+        }
+        Type::Tuple(tuple) => {
+            out.push('(');
+            write_separated(out, &tuple.elems, ", ", write_type);
+            if tuple.elems.len() == 1 {
+                out.push(',');
+            }
+            out.push(')');
+        }
+        Type::Paren(paren) => {
+            out.push('(');
+            write_type(out, &paren.elem);
+            out.push(')');
+        }
+        Type::Group(group) => write_type(out, &group.elem),
+        Type::Path(path) => write_path(out, path.qself.as_ref(), &path.path),
+        Type::TraitObject(object) => {
+            out.push_str("dyn ");
+            write_separated(out, &object.bounds, " + ", |out, bound| match bound {
+                TypeParamBound::Trait(bound) => write_path(out, None, &bound.path),
+                _ => out.push_str(&bound.to_token_stream().to_string()),
+            });
+        }
+        Type::BareFn(function) => {
+            out.push_str("fn(");
+            write_separated(out, &function.inputs, ", ", |out, arg| {
+                write_type(out, &arg.ty)
+            });
+            out.push(')');
+            write_return_type(out, &function.output);
+        }
+        Type::Never(_) => out.push('!'),
+        Type::Infer(_) => out.push('_'),
+        _ => out.push_str(&ty.to_token_stream().to_string()),
+    }
+}
+
+/// Writes path, which is `<T as Trait>::Item` if there is a qualified self type.
+fn write_path(out: &mut String, qself: Option<&QSelf>, path: &Path) {
+    let mut segments = path.segments.iter();
+    let leading_colon = if path.leading_colon.is_some() {
+        "::"
+    } else {
+        ""
+    };
+    if let Some(qself) = qself {
+        out.push('<');
+        write_type(out, &qself.ty);
+        if qself.position > 0 {
+            out.push_str(" as ");
+            out.push_str(leading_colon);
+            write_separated(
+                out,
+                segments.by_ref().take(qself.position),
+                "::",
+                write_segment,
+            );
+        }
+        out.push_str(">::");
+    } else {
+        out.push_str(leading_colon);
+    }
+    write_separated(out, segments, "::", write_segment);
+}
+
+fn write_segment(out: &mut String, segment: &PathSegment) {
+    out.push_str(&segment.ident.to_string());
+    match &segment.arguments {
+        PathArguments::None => {}
+        PathArguments::AngleBracketed(args) => {
+            out.push('<');
+            write_separated(out, &args.args, ", ", |out, arg| match arg {
+                GenericArgument::Type(ty) => write_type(out, ty),
+                GenericArgument::AssocType(assoc) => {
+                    out.push_str(&assoc.ident.to_string());
+                    out.push_str(" = ");
+                    write_type(out, &assoc.ty);
+                }
+                _ => out.push_str(&arg.to_token_stream().to_string()),
+            });
+            out.push('>');
+        }
+        PathArguments::Parenthesized(args) => {
+            out.push('(');
+            write_separated(out, &args.inputs, ", ", write_type);
+            out.push(')');
+            write_return_type(out, &args.output);
+        }
+    }
+}
+
+fn write_return_type(out: &mut String, output: &ReturnType) {
+    if let ReturnType::Type(_, ty) = output {
+        out.push_str(" -> ");
+        write_type(out, ty);
+    }
+}
+
+/// Writes items with a separator between them.
+fn write_separated<'a, T: 'a>(
+    out: &mut String,
+    items: impl IntoIterator<Item = &'a T>,
+    separator: &str,
+    write: impl Fn(&mut String, &'a T),
+) {
+    for (i, item) in items.into_iter().enumerate() {
+        if i > 0 {
+            out.push_str(separator);
+        }
+        write(out, item);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+    use syn::parse_quote;
+
+    use super::{expand_circuit_input, type_to_string};
+
+    #[test]
+    fn named_struct_expansion() {
+        let input = parse_quote! {
+            struct Point {
+                x: PallasBase,
+                y: [PallasBase; 2],
+            }
+        };
+        let doc = "Assigner inputs are the fields below in order, nested structs are flattened:\n\
+                   \n1. `x`: `PallasBase`\
+                   \n2. `y`: `[PallasBase; 2]`";
+        let krate = quote!(::zkllvm_rslang_types);
+        let expected = quote! {
+            #[doc = #doc]
+            impl #krate::CircuitInput for Point {
+                fn write_inputs(
+                    &self,
+                    inputs: &mut ::std::vec::Vec<#krate::JsonValue>
+                ) {
+                    #krate::CircuitInput::write_inputs(&self.x, inputs);
+                    #krate::CircuitInput::write_inputs(&self.y, inputs);
+                }
+
+                fn read_inputs(
+                    inputs: &mut #krate::InputReader<'_>,
+                ) -> ::std::result::Result<Self, #krate::AssignerError> {
+                    let __field0 = <PallasBase as #krate::CircuitInput>::read_inputs(inputs)?;
+                    let __field1 = <[PallasBase; 2] as #krate::CircuitInput>::read_inputs(inputs)?;
+                    ::std::result::Result::Ok(Self { x: __field0, y: __field1 })
+                }
+            }
+        };
+        assert_eq!(
+            expand_circuit_input(input).unwrap().to_string(),
+            expected.to_string()
+        );
+    }
+
+    #[test]
+    fn tuple_struct_expansion() {
+        let input = parse_quote! {
+            struct Pair(PallasBase, Vec<Pallas>);
+        };
+        let expanded = expand_circuit_input(input).unwrap().to_string();
+        let doc = "\n1. `0`: `PallasBase`\n2. `1`: `Vec<Pallas>`";
+        assert!(expanded.contains(&quote!(#doc).to_string()[1..]));
+        assert!(expanded.contains(&quote!(&self.0, inputs).to_string()));
+        assert!(expanded.contains(&quote!(&self.1, inputs).to_string()));
+        assert!(expanded.contains(&quote!(Ok(Self(__field0, __field1))).to_string()));
+    }
+
+    #[test]
+    fn nested_struct_expansion() {
+        let input = parse_quote! {
+            struct Outer<T> {
+                inner: Inner<T>,
+                pair: (Pair, T),
+            }
+        };
+        let expanded = expand_circuit_input(input).unwrap().to_string();
+        let doc = "\n1. `inner`: `Inner<T>`\n2. `pair`: `(Pair, T)`";
+        assert!(expanded.contains(&quote!(#doc).to_string()[1..]));
+        let bounds = quote! {
+            where
+                Inner<T>: ::zkllvm_rslang_types::CircuitInput,
+                (Pair, T): ::zkllvm_rslang_types::CircuitInput
+        };
+        assert!(expanded.contains(&bounds.to_string()));
+        assert!(expanded.contains(
+            &quote!(<Inner<T> as ::zkllvm_rslang_types::CircuitInput>::read_inputs).to_string()
+        ));
+    }
+
+    #[test]
+    fn unsupported_shapes() {
+        for input in [
+            parse_quote!(
+                enum Input {
+                    A(PallasBase),
+                }
+            ),
+            parse_quote!(
+                union Input {
+                    a: u64,
+                }
+            ),
+        ] {
+            let err = expand_circuit_input(input).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "`CircuitInput` can only be derived for structs"
+            );
+        }
+    }
+
+    #[test]
+    fn type_formatting() {
+        for ty in [
+            "PallasBase",
+            "[PallasBase; 4]",
+            "[[Pallas; N]; 2 * N]",
+            "Vec<(PallasBase, Pallas)>",
+            "(PallasBase,)",
+            "()",
+            "&'a [u8]",
+            "&mut T",
+            "*const T",
+            "::zkllvm_rslang_types::PallasBase",
+            "<T as Trait>::Input",
+            "<T as ::path::Trait>::Input",
+            "<T>::Input",
+            "Box<dyn Fn(PallasBase) -> Pallas + Send>",
+            "Option<fn(u8) -> u8>",
+            "Iter<Item = PallasBase>",
+            "Array<PallasBase, 3>",
+        ] {
+            assert_eq!(type_to_string(&syn::parse_str(ty).unwrap()), ty);
+        }
+    }
+}
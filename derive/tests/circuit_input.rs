@@ -0,0 +1,77 @@
+use zkllvm_rslang_types::{
+    field, from_assigner_json, to_assigner_json, AssignerErrorKind, CircuitInput, Pallas,
+    PallasBase,
+};
+
+#[derive(CircuitInput, Debug, PartialEq)]
+struct Named {
+    x: PallasBase,
+    ys: [PallasBase; 2],
+}
+
+#[derive(CircuitInput, Debug, PartialEq)]
+struct Tuple(PallasBase, Vec<PallasBase>);
+
+#[derive(CircuitInput, Debug, PartialEq)]
+struct Unit;
+
+#[derive(CircuitInput, Debug, PartialEq)]
+struct Nested<T> {
+    named: Named,
+    tuple: Tuple,
+    unit: Unit,
+    generic: (T, Pallas),
+}
+
+#[test]
+fn named_struct() {
+    let input = Named {
+        x: field!(PallasBase, 1),
+        ys: [field!(PallasBase, 2), field!(PallasBase, 3)],
+    };
+    let json = to_assigner_json(&input);
+    assert_eq!(
+        json,
+        "[\n  {\"field\": 1},\n  {\"array\": [{\"field\": 2}, {\"field\": 3}]}\n]\n"
+    );
+    assert_eq!(from_assigner_json::<Named>(&json), Ok(input));
+}
+
+#[test]
+fn tuple_struct() {
+    let input = Tuple(field!(PallasBase, 1), vec![field!(PallasBase, 2)]);
+    let json = to_assigner_json(&input);
+    assert_eq!(
+        json,
+        "[\n  {\"field\": 1},\n  {\"vector\": [{\"field\": 2}]}\n]\n"
+    );
+    assert_eq!(from_assigner_json::<Tuple>(&json), Ok(input));
+}
+
+#[test]
+fn unit_struct() {
+    assert_eq!(to_assigner_json(&Unit), "[\n]\n");
+    assert_eq!(from_assigner_json::<Unit>("[]"), Ok(Unit));
+}
+
+#[test]
+fn nested_struct() {
+    let input = Nested {
+        named: Named {
+            x: field!(PallasBase, 1),
+            ys: [field!(PallasBase, 2), field!(PallasBase, 3)],
+        },
+        tuple: Tuple(field!(PallasBase, 4), vec![]),
+        unit: Unit,
+        generic: (field!(PallasBase, 5), Pallas::one()),
+    };
+    let json = to_assigner_json(&input);
+    let mut inputs = Vec::new();
+    input.write_inputs(&mut inputs);
+    assert_eq!(inputs.len(), 6);
+    assert_eq!(from_assigner_json::<Nested<PallasBase>>(&json), Ok(input));
+
+    let err = from_assigner_json::<Nested<PallasBase>>("[{\"field\": 1}]").unwrap_err();
+    assert_eq!(err.path(), "$[1]");
+    assert_eq!(err.kind(), &AssignerErrorKind::MissingInput);
+}
@@ -1,19 +1,24 @@
 //! Implementations designed specifically for [`arkworks`](https://arkworks.rs/).
 
-use std::iter::Once;
+use std::iter::{self, Once};
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
 
-use ark_ff::{Field, Fp256, Fp384, MontBackend, MontConfig};
+use ark_ff::{BigInt, FftField, Field, Fp, Fp256, Fp384, MontBackend, MontConfig, PrimeField};
 use ark_ff::fields::{LegendreSymbol, SqrtPrecomputation};
 use ark_serialize::{
-    CanonicalSerialize, CanonicalSerializeWithFlags, CanonicalDeserialize,
-    CanonicalDeserializeWithFlags, Compress, Flags, SerializationError, Valid, Validate,
+    buffer_byte_size, CanonicalSerialize, CanonicalSerializeWithFlags, CanonicalDeserialize,
+    CanonicalDeserializeWithFlags, Compress, EmptyFlags, Flags, SerializationError, Valid,
+    Validate,
 };
 use ark_std::UniformRand;
 use ark_std::rand::Rng;
 use ark_std::io::{Write, Read};
+use num_bigint::BigUint;
 
-use super::{Bls12381Base, Bls12381Scalar, Curve25519Base, Curve25519Scalar, PallasBase, PallasScalar};
+use super::{
+    Bls12381Base, Bls12381Scalar, Curve25519Base, Curve25519Scalar, PallasBase, PallasScalar,
+    ZkField,
+};
 
 /// Implements `op<&mut Self>` for all arithmetic operations.
 ///
@@ -111,16 +116,22 @@ mut_arith_impl! {
     PallasScalar
 }
 
-/// Implements [`UniformRand`].
+/// Implements [`UniformRand`] by rejection sampling:
+/// random bytes are truncated to the modulus bit size until they encode a canonical value.
 macro_rules! uniform_rand_impl {
     ($($t:ty)*) => ($(
         impl UniformRand for $t {
-            fn rand<R: ?Sized>(_: &mut R) -> Self
-            where
-                R: Rng
-            {
-                // TODO: actually now I don't know what to do here.
-                todo!("uniform rand implementation for fields");
+            fn rand<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                const EXCESS_BITS: usize = 8 * <$t>::NUM_BYTES - <$t>::NUM_BITS as usize;
+
+                let mut bytes = [0u8; <$t>::NUM_BYTES];
+                loop {
+                    rng.fill_bytes(&mut bytes);
+                    bytes[<$t>::NUM_BYTES - 1] &= u8::MAX >> EXCESS_BITS;
+                    if let Some(value) = Self::from_bytes_le(&bytes) {
+                        return value;
+                    }
+                }
             }
         }
     )*)
@@ -136,59 +147,90 @@ uniform_rand_impl! {
 }
 
 /// Implements a number of serialization traits.
-/// We actually do not need them, so they left blank.
+///
+/// Encoding is the same as for ark-ff `Fp` types: canonical little-endian bytes
+/// with flags placed into the most significant bits of the last byte,
+/// which is appended if the modulus leaves no spare bits.
 macro_rules! serialize_impl {
     ($($t:ty)*) => ($(
-
         impl CanonicalSerialize for $t {
+            #[inline]
             fn serialize_with_mode<W: Write>(
                 &self,
-                _: W,
+                writer: W,
                 _: Compress,
             ) -> Result<(), SerializationError> {
-                panic!("serialization is impossible");
+                self.serialize_with_flags(writer, EmptyFlags)
             }
 
+            #[inline]
             fn serialized_size(&self, _: Compress) -> usize {
-                panic!("serialization is impossible");
+                self.serialized_size_with_flags::<EmptyFlags>()
             }
         }
 
         impl CanonicalSerializeWithFlags for $t {
             fn serialize_with_flags<W: Write, F: Flags>(
                 &self,
-                _: W,
-                _: F,
+                mut writer: W,
+                flags: F,
             ) -> Result<(), SerializationError> {
-                panic!("serialization is impossible");
+                if F::BIT_SIZE > 8 {
+                    return Err(SerializationError::NotEnoughSpace);
+                }
+                let output_byte_size = self.serialized_size_with_flags::<F>();
+
+                let mut bytes = [0u8; <$t>::NUM_BYTES + 1];
+                bytes[..<$t>::NUM_BYTES].copy_from_slice(&self.to_bytes_le());
+                bytes[output_byte_size - 1] |= flags.u8_bitmask();
+
+                writer.write_all(&bytes[..output_byte_size])?;
+                Ok(())
             }
 
+            #[inline]
             fn serialized_size_with_flags<F: Flags>(&self) -> usize {
-                panic!("serialization is impossible");
+                buffer_byte_size(<$t>::NUM_BITS as usize + F::BIT_SIZE)
             }
         }
 
         impl Valid for $t {
+            #[inline]
             fn check(&self) -> Result<(), SerializationError> {
-                panic!("serialization is impossible");
+                Ok(())
             }
         }
 
         impl CanonicalDeserialize for $t {
+            #[inline]
             fn deserialize_with_mode<R: Read>(
-                _: R,
+                reader: R,
                 _: Compress,
                 _: Validate,
             ) -> Result<Self, SerializationError> {
-                panic!("serialization is impossible");
+                Self::deserialize_with_flags::<R, EmptyFlags>(reader).map(|(value, _)| value)
             }
         }
 
         impl CanonicalDeserializeWithFlags for $t {
             fn deserialize_with_flags<R: Read, F: Flags>(
-                _: R,
+                mut reader: R,
             ) -> Result<(Self, F), SerializationError> {
-                panic!("serialization is impossible");
+                if F::BIT_SIZE > 8 {
+                    return Err(SerializationError::NotEnoughSpace);
+                }
+                let output_byte_size = buffer_byte_size(<$t>::NUM_BITS as usize + F::BIT_SIZE);
+
+                let mut bytes = [0u8; <$t>::NUM_BYTES + 1];
+                reader.read_exact(&mut bytes[..output_byte_size])?;
+                let flags = F::from_u8_remove_flags(&mut bytes[output_byte_size - 1])
+                    .ok_or(SerializationError::UnexpectedFlags)?;
+
+                // Like ark-ff, the byte appended for flags is ignored.
+                let repr = bytes[..<$t>::NUM_BYTES].try_into().unwrap();
+                Self::from_bytes_le(repr)
+                    .map(|value| (value, flags))
+                    .ok_or(SerializationError::InvalidData)
             }
         }
     )*)
@@ -203,68 +245,113 @@ serialize_impl! {
     PallasScalar
 }
 
-#[derive(MontConfig)]
-#[modulus = "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559787"]
-#[generator = "2"]
-#[small_subgroup_base = "3"]
-#[small_subgroup_power = "2"]
-pub struct Bls12381BaseConfig;
-
-#[derive(MontConfig)]
-#[modulus = "52435875175126190479447740508185965837690552500527637822603658699938581184513"]
-#[generator = "7"]
-#[small_subgroup_base = "3"]
-#[small_subgroup_power = "1"]
-pub struct Bls12381ScalarConfig;
-
-#[derive(MontConfig)]
-#[modulus = "57896044618658097711785492504343953926634992332820282019728792003956564819949"]
-#[generator = "2"]
-#[small_subgroup_base = "3"]
-#[small_subgroup_power = "1"]
-pub struct Curve25519BaseConfig;
-
-#[derive(MontConfig)]
-#[modulus = "7237005577332262213973186563042994240857116359379907606001950938285454250989"]
-#[generator = "2"]
-#[small_subgroup_base = "3"]
-#[small_subgroup_power = "1"]
-pub struct Curve25519ScalarConfig;
-
-#[derive(MontConfig)]
-#[modulus = "28948022309329048855892746252171976963363056481941560715954676764349967630337"]
-#[generator = "5"]
-pub struct PallasBaseConfig;
-
-#[derive(MontConfig)]
-#[modulus = "28948022309329048855892746252171976963363056481941647379679742748393362948097"]
-#[generator = "5"]
-pub struct PallasScalarConfig;
+/// Defines `MontConfig` of the ark-ff field with the same modulus as the field wrapper.
+///
+/// Parameters are taken from the wrapper constants, so both fields always agree.
+macro_rules! mont_config_impl {
+    ($($config:ident, $t:ident, $limbs:literal;)*) => ($(
+        pub struct $config;
+
+        impl MontConfig<$limbs> for $config {
+            const MODULUS: BigInt<$limbs> = BigInt(<$t>::MODULUS);
+
+            const GENERATOR: Fp<MontBackend<Self, $limbs>, $limbs> =
+                Fp::new(BigInt(<$t>::GENERATOR_LIMBS));
+
+            const TWO_ADIC_ROOT_OF_UNITY: Fp<MontBackend<Self, $limbs>, $limbs> =
+                Fp::new(BigInt(<$t>::ROOT_OF_UNITY_LIMBS));
+
+            const SMALL_SUBGROUP_BASE: Option<u32> = <$t as FftField>::SMALL_SUBGROUP_BASE;
+
+            const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> =
+                <$t as FftField>::SMALL_SUBGROUP_BASE_ADICITY;
+
+            const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<Fp<MontBackend<Self, $limbs>, $limbs>> =
+                match <$t>::LARGE_SUBGROUP_ROOT_LIMBS {
+                    Some(limbs) => Some(Fp::new(BigInt(limbs))),
+                    None => None,
+                };
+        }
+    )*)
+}
+
+mont_config_impl! {
+    Bls12381BaseConfig, Bls12381Base, 6;
+    Bls12381ScalarConfig, Bls12381Scalar, 4;
+    Curve25519BaseConfig, Curve25519Base, 4;
+    Curve25519ScalarConfig, Curve25519Scalar, 4;
+    PallasBaseConfig, PallasBase, 4;
+    PallasScalarConfig, PallasScalar, 4;
+}
+
+/// Implements lossless conversions between a field wrapper and ark-ff field with the same modulus.
+macro_rules! fp_from_impl {
+    ($($t:ty, $fp:ty)*) => ($(
+        impl From<$fp> for $t {
+            fn from(value: $fp) -> Self {
+                Self::from_le_limbs(value.into_bigint().0)
+                    .expect("canonical limbs are always less than the modulus")
+            }
+        }
+
+        impl From<$t> for $fp {
+            fn from(value: $t) -> Self {
+                <$fp>::from_bigint(BigInt(value.to_le_limbs()))
+                    .expect("canonical limbs are always less than the modulus")
+            }
+        }
+    )*)
+}
+
+fp_from_impl! {
+    Bls12381Base, Fp384<MontBackend<Bls12381BaseConfig, 6>>
+    Bls12381Scalar, Fp256<MontBackend<Bls12381ScalarConfig, 4>>
+    Curve25519Base, Fp256<MontBackend<Curve25519BaseConfig, 4>>
+    Curve25519Scalar, Fp256<MontBackend<Curve25519ScalarConfig, 4>>
+    PallasBase, Fp256<MontBackend<PallasBaseConfig, 4>>
+    PallasScalar, Fp256<MontBackend<PallasScalarConfig, 4>>
+}
 
 /// Implements [`Field`].
 macro_rules! ark_field_impl {
-    ($($t:ty, $bpf:ty)*) => ($(
+    ($($t:ty, $fp:ty)*) => ($(
         impl Field for $t {
-            type BasePrimeField = $bpf;
+            type BasePrimeField = Self;
 
             type BasePrimeFieldIter = Once<Self::BasePrimeField>;
 
-            const SQRT_PRECOMP: Option<SqrtPrecomputation<Self>> = None;
+            const SQRT_PRECOMP: Option<SqrtPrecomputation<Self>> =
+                Some(SqrtPrecomputation::TonelliShanks {
+                    two_adicity: <$t>::TWO_ADICITY,
+                    quadratic_nonresidue_to_trace: <$t as ZkField>::ROOT_OF_UNITY,
+                    trace_of_modulus_minus_one_div_two: &<$t>::TRACE_MINUS_ONE_DIV_TWO,
+                });
 
-            const ZERO: Self = Self(0g);
+            const ZERO: Self = Self(builtin_zero!($t));
 
-            const ONE: Self = Self(1g);
+            const ONE: Self = Self(builtin_one!($t));
 
-            fn extension_degree() -> u64 { todo!() }
+            /// Prime fields are extensions of degree 1 of themselves.
+            fn extension_degree() -> u64 {
+                1
+            }
 
-            fn to_base_prime_field_elements(&self) -> Self::BasePrimeFieldIter { todo!() }
+            fn to_base_prime_field_elements(&self) -> Self::BasePrimeFieldIter {
+                iter::once(*self)
+            }
 
-            fn from_base_prime_field_elems(_elems: &[Self::BasePrimeField]) -> Option<Self> { todo!() }
+            fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Option<Self> {
+                match elems {
+                    [elem] => Some(Self::from_base_prime_field(*elem)),
+                    _ => None,
+                }
+            }
 
-            fn from_base_prime_field(_elem: Self::BasePrimeField) -> Self { todo!() }
+            fn from_base_prime_field(elem: Self::BasePrimeField) -> Self {
+                elem
+            }
 
             /// Returns `self + self`.
-            #[must_use]
             fn double(&self) -> Self {
                 self + self
             }
@@ -287,16 +374,33 @@ macro_rules! ark_field_impl {
             ///
             /// This function is primarily intended for sampling random field elements
             /// from a hash-function or RNG output.
-            fn from_random_bytes_with_flags<F: Flags>(_bytes: &[u8]) -> Option<(Self, F)> { todo!() }
+            ///
+            /// Byte encodings coincide, so this is delegated to the ark-ff field.
+            fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)> {
+                <$fp>::from_random_bytes_with_flags(bytes)
+                    .map(|(elem, flags)| (Self::from(elem), flags))
+            }
 
             /// Returns a `LegendreSymbol`, which indicates whether this field element
             /// is  1 : a quadratic residue
             ///  0 : equal to 0
             /// -1 : a quadratic non-residue
-            fn legendre(&self) -> LegendreSymbol { todo!() }
+            fn legendre(&self) -> LegendreSymbol {
+                match <$t>::legendre(self) {
+                    crate::LegendreSymbol::Zero => LegendreSymbol::Zero,
+                    crate::LegendreSymbol::QuadraticResidue => LegendreSymbol::QuadraticResidue,
+                    crate::LegendreSymbol::QuadraticNonResidue => {
+                        LegendreSymbol::QuadraticNonResidue
+                    }
+                }
+            }
+
+            /// Returns the square root of `self`, if it exists.
+            fn sqrt(&self) -> Option<Self> {
+                <$t>::sqrt(self)
+            }
 
             /// Returns `self * self`.
-            #[must_use]
             fn square(&self) -> Self {
                 self * self
             }
@@ -308,12 +412,10 @@ macro_rules! ark_field_impl {
             }
 
             /// Computes the multiplicative inverse of `self` if `self` is nonzero.
-            #[must_use]
             fn inverse(&self) -> Option<Self> {
                 if self == &Self::ZERO {
                     None
                 } else {
-                    // TODO: is this correct?
                     Some(Self::ONE / self)
                 }
             }
@@ -329,7 +431,9 @@ macro_rules! ark_field_impl {
 
             /// Sets `self` to `self^s`, where `s = Self::BasePrimeField::MODULUS^power`.
             /// This is also called the Frobenius automorphism.
-            fn frobenius_map_in_place(&mut self, _power: usize) { todo!() }
+            ///
+            /// The Frobenius map has no effect in a prime field.
+            fn frobenius_map_in_place(&mut self, _power: usize) {}
         }
     )*)
 }
@@ -342,3 +446,340 @@ ark_field_impl! {
     PallasBase, Fp256<MontBackend<PallasBaseConfig, 4>>
     PallasScalar, Fp256<MontBackend<PallasScalarConfig, 4>>
 }
+
+/// Implements [`PrimeField`] and traits it requires.
+///
+/// Constants are taken from the ark-ff field with the same modulus, so they always agree.
+macro_rules! ark_prime_field_impl {
+    ($($t:ty, $fp:ty, $limbs:literal)*) => ($(
+        impl PrimeField for $t {
+            type BigInt = BigInt<$limbs>;
+
+            const MODULUS: Self::BigInt = <$fp>::MODULUS;
+
+            const MODULUS_MINUS_ONE_DIV_TWO: Self::BigInt = <$fp>::MODULUS_MINUS_ONE_DIV_TWO;
+
+            const MODULUS_BIT_SIZE: u32 = <$fp>::MODULUS_BIT_SIZE;
+
+            const TRACE: Self::BigInt = <$fp>::TRACE;
+
+            const TRACE_MINUS_ONE_DIV_TWO: Self::BigInt = <$fp>::TRACE_MINUS_ONE_DIV_TWO;
+
+            /// Constructs a field element from its canonical integer representation.
+            ///
+            /// Returns `None` if the integer is not less than the modulus.
+            fn from_bigint(repr: Self::BigInt) -> Option<Self> {
+                Self::from_le_limbs(repr.0)
+            }
+
+            /// Returns canonical integer representation of the field element.
+            fn into_bigint(self) -> Self::BigInt {
+                BigInt(self.to_le_limbs())
+            }
+        }
+
+        impl From<BigInt<$limbs>> for $t {
+            /// Panics if the integer is not less than the modulus, like ark-ff does.
+            fn from(repr: BigInt<$limbs>) -> Self {
+                Self::from_bigint(repr).unwrap()
+            }
+        }
+
+        impl From<$t> for BigInt<$limbs> {
+            fn from(value: $t) -> Self {
+                value.into_bigint()
+            }
+        }
+
+        impl From<BigUint> for $t {
+            /// Reduces the integer modulo field modulus.
+            fn from(value: BigUint) -> Self {
+                Self::from_bytes_le_mod_order(&value.to_bytes_le())
+            }
+        }
+
+        impl From<$t> for BigUint {
+            fn from(value: $t) -> Self {
+                value.into_bigint().into()
+            }
+        }
+    )*)
+}
+
+ark_prime_field_impl! {
+    Bls12381Base, Fp384<MontBackend<Bls12381BaseConfig, 6>>, 6
+    Bls12381Scalar, Fp256<MontBackend<Bls12381ScalarConfig, 4>>, 4
+    Curve25519Base, Fp256<MontBackend<Curve25519BaseConfig, 4>>, 4
+    Curve25519Scalar, Fp256<MontBackend<Curve25519ScalarConfig, 4>>, 4
+    PallasBase, Fp256<MontBackend<PallasBaseConfig, 4>>, 4
+    PallasScalar, Fp256<MontBackend<PallasScalarConfig, 4>>, 4
+}
+
+/// Expands to `Some` of the expression if it is given, and to `None` otherwise.
+macro_rules! option_expr {
+    () => {
+        None
+    };
+    ($e:expr) => {
+        Some($e)
+    };
+}
+
+/// Implements [`FftField`].
+///
+/// Generators and two-adic roots of unity are taken from [`ZkField`].
+/// Large subgroup roots of unity are `GENERATOR^((p - 1) / (2^s * base^adicity))`,
+/// `MontConfig` structs above reuse them.
+macro_rules! ark_fft_field_impl {
+    ($($t:ident, $limbs:literal
+       $(, $small_subgroup_base:literal ^ $small_subgroup_adicity:literal,
+           $large_subgroup_root:tt, $large_subgroup_root_limbs:expr)?;)*) => ($(
+        impl $t {
+            /// Large subgroup root of unity as little-endian limbs.
+            const LARGE_SUBGROUP_ROOT_LIMBS: Option<[u64; $limbs]> =
+                option_expr!($($large_subgroup_root_limbs)?);
+        }
+
+        impl FftField for $t {
+            const GENERATOR: Self = <$t as ZkField>::MULTIPLICATIVE_GENERATOR;
+
+            const TWO_ADICITY: u32 = <$t as ZkField>::TWO_ADICITY;
+
+            const TWO_ADIC_ROOT_OF_UNITY: Self = <$t as ZkField>::ROOT_OF_UNITY;
+
+            $(
+                const SMALL_SUBGROUP_BASE: Option<u32> = Some($small_subgroup_base);
+
+                const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = Some($small_subgroup_adicity);
+
+                const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<Self> =
+                    Some(field_const!($t, $large_subgroup_root, $large_subgroup_root_limbs));
+            )?
+        }
+    )*)
+}
+
+ark_fft_field_impl! {
+    Bls12381Base, 6,
+    3 ^ 2,
+    2988978003585557947763075667640039217377002497190305284839487944917064303631503031957276795389570612581055436501608g,
+    [
+        0x51d3d95bc6353268, 0x4c61a69d08cdacea, 0x9f2173c050e203f3,
+        0xed5af75dc443f6ac, 0x648619ecf3afa71f, 0x136b76d4878fbec7,
+    ];
+
+    Bls12381Scalar, 4,
+    3 ^ 1,
+    27190014779860110908007675333221278800259766657973320723066279455788162251031g,
+    [0x02b93785357e7917, 0x85aedb297ca15150, 0xea45ce5f9f533109, 0x3c1d00c4965f33c8];
+
+    Curve25519Base, 4,
+    3 ^ 1,
+    46303522732899497345428873723136134614853677474890508840780446176533364673768g,
+    [0xede68f9c80010ce8, 0x1481d35478027bb2, 0xbd6f2d9d5880b917, 0x665edd79319379b5];
+
+    Curve25519Scalar, 4,
+    3 ^ 1,
+    2720847846751491041772832284972719674122581991060822408323025541677958938475g,
+    [0x0d0d497569e1bf6b, 0xd9f2eed3c315c627, 0xdbde6707b619a7fb, 0x0603f1ffaebbc3e9];
+
+    PallasBase, 4;
+
+    PallasScalar, 4;
+}
+
+#[cfg(all(test, feature = "software"))]
+mod tests {
+    use super::*;
+
+    fn to_biguint<F: PrimeField>(x: F) -> BigUint {
+        x.into_bigint().into()
+    }
+
+    /// Generates tests of FFT constants against the fields of `ark-bls12-381`.
+    macro_rules! ark_bls12_381_tests {
+        ($($name:ident, $t:ty, $fp:ty, $known:ty;)*) => ($(
+            #[test]
+            fn $name() {
+                assert_eq!(to_biguint(<$fp>::from(<$t>::ONE)), BigUint::from(1u8));
+                assert_eq!(BigUint::from(<$fp>::MODULUS), BigUint::from(<$known>::MODULUS));
+                assert_eq!(
+                    to_biguint(<$fp>::from(<$t as FftField>::GENERATOR)),
+                    to_biguint(<$known>::GENERATOR),
+                );
+                assert_eq!(<$t as FftField>::TWO_ADICITY, <$known>::TWO_ADICITY);
+                assert_eq!(
+                    to_biguint(<$fp>::from(<$t as FftField>::TWO_ADIC_ROOT_OF_UNITY)),
+                    to_biguint(<$known>::TWO_ADIC_ROOT_OF_UNITY),
+                );
+            }
+        )*)
+    }
+
+    ark_bls12_381_tests! {
+        bls12381_base_fft_constants, Bls12381Base, Fp384<MontBackend<Bls12381BaseConfig, 6>>,
+            ark_bls12_381::Fq;
+        bls12381_scalar_fft_constants, Bls12381Scalar, Fp256<MontBackend<Bls12381ScalarConfig, 4>>,
+            ark_bls12_381::Fr;
+    }
+
+    /// Generates tests of FFT constants against decimal literals.
+    macro_rules! fft_constants_tests {
+        ($($name:ident, $t:ty, $fp:ty,
+           $modulus:expr, $generator:expr, $s:expr, $root:expr;)*) => ($(
+            #[test]
+            fn $name() {
+                let parse = |decimal: &str| decimal.parse::<BigUint>().unwrap();
+                assert_eq!(BigUint::from(<$fp>::MODULUS), parse($modulus));
+                assert_eq!(
+                    to_biguint(<$fp>::from(<$t as FftField>::GENERATOR)),
+                    BigUint::from($generator),
+                );
+                assert_eq!(<$t as FftField>::TWO_ADICITY, $s);
+                assert_eq!(
+                    to_biguint(<$fp>::from(<$t as FftField>::TWO_ADIC_ROOT_OF_UNITY)),
+                    parse($root),
+                );
+            }
+        )*)
+    }
+
+    // Source: `GENERATOR` and `ROOT_OF_UNITY` of `pasta_curves` 0.5.1 `Fp` and `Fq`;
+    // for Curve25519 base field, `sqrt(-1) = 2^((p - 1) / 4)` of RFC 8032, Section 5.1.3,
+    // and `2^((l - 1) / 4)` for its scalar field.
+    fft_constants_tests! {
+        curve25519_base_fft_constants, Curve25519Base, Fp256<MontBackend<Curve25519BaseConfig, 4>>,
+            "57896044618658097711785492504343953926634992332820282019728792003956564819949",
+            2u8, 2,
+            "19681161376707505956807079304988542015446066515923890162744021073123829784752";
+        curve25519_scalar_fft_constants, Curve25519Scalar,
+            Fp256<MontBackend<Curve25519ScalarConfig, 4>>,
+            "7237005577332262213973186563042994240857116359379907606001950938285454250989",
+            2u8, 2,
+            "4202356475871964119699734399548423449193549369991576068503119564443318355924";
+        pallas_base_fft_constants, PallasBase, Fp256<MontBackend<PallasBaseConfig, 4>>,
+            "28948022309329048855892746252171976963363056481941560715954676764349967630337",
+            5u8, 32,
+            "19814229590243028906643993866117402072516588566294623396325693409366934201135";
+        pallas_scalar_fft_constants, PallasScalar, Fp256<MontBackend<PallasScalarConfig, 4>>,
+            "28948022309329048855892746252171976963363056481941647379679742748393362948097",
+            5u8, 32,
+            "20761624379169977859705911634190121761503565370703356079647768903521299517535";
+    }
+
+    /// Two-bit flags, which take the spare bits of some moduli and a separate byte of others.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct TwoBitFlags(u8);
+
+    impl Flags for TwoBitFlags {
+        const BIT_SIZE: usize = 2;
+
+        fn u8_bitmask(&self) -> u8 {
+            self.0 << 6
+        }
+
+        fn from_u8(value: u8) -> Option<Self> {
+            Some(Self(value >> 6))
+        }
+    }
+
+    /// Generates tests of consistency with ark-ff fields.
+    macro_rules! ark_consistency_tests {
+        ($($mod:ident, $t:ty, $fp:ty;)*) => ($(
+            mod $mod {
+                use super::*;
+
+                #[test]
+                fn inverse_matches() {
+                    assert_eq!(Field::inverse(&<$t>::ZERO), None);
+                    assert_eq!(Field::inverse(&<$t>::ONE), Some(<$t>::ONE));
+
+                    let mut x = <$t>::from_bytes_le_mod_order(b"inverse test vector");
+                    for _ in 0..8 {
+                        let inv = Field::inverse(&x).unwrap();
+                        assert_eq!(x * inv, <$t>::ONE);
+                        assert_eq!(<$fp>::from(inv), <$fp>::from(x).inverse().unwrap());
+
+                        let mut y = x;
+                        assert_eq!(y.inverse_in_place().copied(), Some(inv));
+                        x = x * x + <$t>::ONE;
+                    }
+                }
+
+                #[test]
+                fn uniform_rand() {
+                    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+                    let mut rng = StdRng::seed_from_u64(0x5eed);
+                    let samples: Vec<$t> = (0..64).map(|_| <$t>::rand(&mut rng)).collect();
+                    let mut rng = StdRng::seed_from_u64(0x5eed);
+                    for &x in &samples {
+                        assert_eq!(<$t>::rand(&mut rng), x);
+                    }
+
+                    // Samples are canonical and the most significant bits get used.
+                    let modulus = BigUint::from(<$fp>::MODULUS);
+                    let half = &modulus >> 1u8;
+                    let mut values: Vec<BigUint> =
+                        samples.iter().map(|&x| to_biguint(<$fp>::from(x))).collect();
+                    assert!(values.iter().all(|value| value < &modulus));
+                    assert!(values.iter().any(|value| value >= &half));
+                    assert!(values.iter().any(|value| value < &half));
+                    values.sort();
+                    values.dedup();
+                    assert_eq!(values.len(), samples.len());
+                }
+
+                #[test]
+                fn serialization_matches() {
+                    let mut x = -<$t>::ONE;
+                    for i in 0..8u8 {
+                        let flags = TwoBitFlags(i % 4);
+                        for compress in [Compress::Yes, Compress::No] {
+                            let mut bytes = Vec::new();
+                            x.serialize_with_mode(&mut bytes, compress).unwrap();
+                            let mut expected = Vec::new();
+                            <$fp>::from(x).serialize_with_mode(&mut expected, compress).unwrap();
+                            assert_eq!(bytes, expected);
+                            assert_eq!(x.serialized_size(compress), bytes.len());
+                            let y = <$t>::deserialize_with_mode(&bytes[..], compress, Validate::Yes)
+                                .unwrap();
+                            assert_eq!(y, x);
+                        }
+
+                        let mut bytes = Vec::new();
+                        x.serialize_with_flags(&mut bytes, flags).unwrap();
+                        let mut expected = Vec::new();
+                        <$fp>::from(x).serialize_with_flags(&mut expected, flags).unwrap();
+                        assert_eq!(bytes, expected);
+                        assert_eq!(x.serialized_size_with_flags::<TwoBitFlags>(), bytes.len());
+                        let (y, f) = <$t>::deserialize_with_flags::<_, TwoBitFlags>(&bytes[..])
+                            .unwrap();
+                        assert_eq!((y, f), (x, flags));
+
+                        x = x * x + <$t>::ONE;
+                    }
+
+                    let modulus: Vec<u8> = <$t as ZkField>::MODULUS
+                        .iter()
+                        .flat_map(|limb| limb.to_le_bytes())
+                        .collect();
+                    assert!(matches!(
+                        <$t>::deserialize_compressed(&modulus[..]),
+                        Err(SerializationError::InvalidData),
+                    ));
+                    assert!(<$t>::deserialize_compressed(&modulus[1..]).is_err());
+                }
+            }
+        )*)
+    }
+
+    ark_consistency_tests! {
+        bls12381_base, Bls12381Base, Fp384<MontBackend<Bls12381BaseConfig, 6>>;
+        bls12381_scalar, Bls12381Scalar, Fp256<MontBackend<Bls12381ScalarConfig, 4>>;
+        curve25519_base, Curve25519Base, Fp256<MontBackend<Curve25519BaseConfig, 4>>;
+        curve25519_scalar, Curve25519Scalar, Fp256<MontBackend<Curve25519ScalarConfig, 4>>;
+        pallas_base, PallasBase, Fp256<MontBackend<PallasBaseConfig, 4>>;
+        pallas_scalar, PallasScalar, Fp256<MontBackend<PallasScalarConfig, 4>>;
+    }
+}
@@ -0,0 +1,384 @@
+//! Minimal JSON document model, parser and serializer.
+
+use std::fmt::{self, Write};
+
+/// JSON value.
+///
+/// Numbers are kept as their textual representation, so integers of any size survive
+/// a round trip.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum JsonValue {
+    /// `null`.
+    Null,
+    /// `true` or `false`.
+    Bool(bool),
+    /// Number in its textual representation.
+    Number(String),
+    /// String.
+    String(String),
+    /// Array.
+    Array(Vec<JsonValue>),
+    /// Object with members in document order.
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    /// Creates object with a single member.
+    pub fn singleton(key: &str, value: JsonValue) -> Self {
+        JsonValue::Object(vec![(key.to_owned(), value)])
+    }
+
+    /// Returns the only member of an object, if the value is an object with a single member.
+    pub fn as_singleton(&self) -> Option<(&str, &JsonValue)> {
+        match self {
+            JsonValue::Object(members) if members.len() == 1 => {
+                Some((members[0].0.as_str(), &members[0].1))
+            }
+            _ => None,
+        }
+    }
+
+    /// Parses JSON document.
+    ///
+    /// Returns byte offset of the first error on failure.
+    /// Arrays and objects nested deeper than 128 levels are rejected,
+    /// so that untrusted input cannot overflow the stack.
+    pub fn parse(text: &str) -> Result<Self, usize> {
+        let mut parser = Parser {
+            text: text.as_bytes(),
+            pos: 0,
+            depth: 0,
+        };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        if parser.pos != parser.text.len() {
+            return Err(parser.pos);
+        }
+        Ok(value)
+    }
+}
+
+impl fmt::Display for JsonValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonValue::Null => f.write_str("null"),
+            JsonValue::Bool(b) => write!(f, "{}", b),
+            JsonValue::Number(n) => f.write_str(n),
+            JsonValue::String(s) => write_string(f, s),
+            JsonValue::Array(items) => {
+                f.write_char('[')?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                f.write_char(']')
+            }
+            JsonValue::Object(members) => {
+                f.write_char('{')?;
+                for (i, (key, value)) in members.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ": {}", value)?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+/// Writes quoted and escaped string.
+fn write_string(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+/// Maximum nesting depth of arrays and objects accepted by [`JsonValue::parse`].
+const MAX_DEPTH: usize = 128;
+
+/// Recursive descent parser, errors are byte offsets.
+struct Parser<'a> {
+    text: &'a [u8],
+    pos: usize,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.text.get(self.pos).copied()
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), usize> {
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.pos)
+        }
+    }
+
+    fn keyword(&mut self, keyword: &str, value: JsonValue) -> Result<JsonValue, usize> {
+        if self.text[self.pos..].starts_with(keyword.as_bytes()) {
+            self.pos += keyword.len();
+            Ok(value)
+        } else {
+            Err(self.pos)
+        }
+    }
+
+    fn value(&mut self) -> Result<JsonValue, usize> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'n') => self.keyword("null", JsonValue::Null),
+            Some(b't') => self.keyword("true", JsonValue::Bool(true)),
+            Some(b'f') => self.keyword("false", JsonValue::Bool(false)),
+            Some(b'"') => self.string().map(JsonValue::String),
+            Some(b'[' | b'{') => self.nested(),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            _ => Err(self.pos),
+        }
+    }
+
+    fn digits(&mut self) -> Result<(), usize> {
+        let start = self.pos;
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.pos += 1;
+        }
+        if self.pos == start {
+            Err(self.pos)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn number(&mut self) -> Result<JsonValue, usize> {
+        let start = self.pos;
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        if self.peek() == Some(b'0') {
+            self.pos += 1;
+        } else {
+            self.digits()?;
+        }
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            self.digits()?;
+        }
+        if let Some(b'e' | b'E') = self.peek() {
+            self.pos += 1;
+            if let Some(b'+' | b'-') = self.peek() {
+                self.pos += 1;
+            }
+            self.digits()?;
+        }
+        // Only ASCII bytes are consumed above.
+        let text = std::str::from_utf8(&self.text[start..self.pos]).unwrap();
+        Ok(JsonValue::Number(text.to_owned()))
+    }
+
+    fn hex4(&mut self) -> Result<u32, usize> {
+        let digits = self.text.get(self.pos..self.pos + 4).ok_or(self.pos)?;
+        let mut code = 0;
+        for &digit in digits {
+            code = code * 16 + (digit as char).to_digit(16).ok_or(self.pos)?;
+        }
+        self.pos += 4;
+        Ok(code)
+    }
+
+    fn string(&mut self) -> Result<String, usize> {
+        self.expect(b'"')?;
+        let mut res = Vec::new();
+        loop {
+            let byte = self.peek().ok_or(self.pos)?;
+            self.pos += 1;
+            match byte {
+                b'"' => break,
+                b'\\' => {
+                    let escape = self.peek().ok_or(self.pos)?;
+                    self.pos += 1;
+                    let c = match escape {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => {
+                            let start = self.pos;
+                            let mut code = self.hex4()?;
+                            if (0xd800..0xdc00).contains(&code) {
+                                // High surrogate must be followed by a low one.
+                                self.expect(b'\\')?;
+                                self.expect(b'u')?;
+                                let low = self.hex4()?;
+                                if !(0xdc00..0xe000).contains(&low) {
+                                    return Err(start);
+                                }
+                                code = 0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00);
+                            }
+                            char::from_u32(code).ok_or(start)?
+                        }
+                        _ => return Err(self.pos - 1),
+                    };
+                    res.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                }
+                0x00..=0x1f => return Err(self.pos - 1),
+                _ => res.push(byte),
+            }
+        }
+        // The input is a `str` and escapes produce valid UTF-8.
+        Ok(String::from_utf8(res).unwrap())
+    }
+
+    /// Parses array or object, one level deeper.
+    fn nested(&mut self) -> Result<JsonValue, usize> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.pos);
+        }
+        self.depth += 1;
+        let value = if self.peek() == Some(b'[') {
+            self.array()
+        } else {
+            self.object()
+        };
+        self.depth -= 1;
+        value
+    }
+
+    fn array(&mut self) -> Result<JsonValue, usize> {
+        self.expect(b'[')?;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(JsonValue::Array(items));
+        }
+        loop {
+            items.push(self.value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(JsonValue::Array(items));
+                }
+                _ => return Err(self.pos),
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<JsonValue, usize> {
+        self.expect(b'{')?;
+        let mut members = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(JsonValue::Object(members));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(b':')?;
+            members.push((key, self.value()?));
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(JsonValue::Object(members));
+                }
+                _ => return Err(self.pos),
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "software"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let text =
+            r#"[{"field": 12}, {"curve": ["0x1", "0x2"]}, null, true, false, -1.5e+3, {}, []]"#;
+        let value = JsonValue::parse(text).unwrap();
+        assert_eq!(value.to_string(), text);
+
+        let escaped = JsonValue::String("\"\\\n\r\t\u{1}é".to_owned());
+        assert_eq!(escaped.to_string(), r#""\"\\\n\r\t\u0001é""#);
+        assert_eq!(JsonValue::parse(&escaped.to_string()), Ok(escaped));
+    }
+
+    #[test]
+    fn parse_whitespace_and_escapes() {
+        let value = JsonValue::parse(" {\n\t\"a\\/b\" : [ 1 , \"\\ud83d\\ude00\" ] }\r\n").unwrap();
+        let expected = JsonValue::singleton(
+            "a/b",
+            JsonValue::Array(vec![
+                JsonValue::Number("1".to_owned()),
+                JsonValue::String("\u{1f600}".to_owned()),
+            ]),
+        );
+        assert_eq!(value, expected);
+    }
+
+    #[test]
+    fn malformed() {
+        for (text, offset) in [
+            ("", 0),
+            ("[1, 2", 5),
+            ("[1 2]", 3),
+            ("[1,]", 3),
+            ("{\"a\" 1}", 5),
+            ("{1: 2}", 1),
+            ("nul", 0),
+            ("01", 1),
+            ("1.", 2),
+            ("-", 1),
+            ("\"abc", 4),
+            ("\"\\x\"", 2),
+            ("\"\\ud83d\"", 7),
+            ("\"\\udc00\\u0041\"", 3),
+            ("\"\n\"", 1),
+            ("[] []", 3),
+        ] {
+            assert_eq!(JsonValue::parse(text), Err(offset), "{:?}", text);
+        }
+    }
+
+    #[test]
+    fn nesting_limit() {
+        let nested = |depth| "[".repeat(depth) + &"]".repeat(depth);
+        assert!(JsonValue::parse(&nested(MAX_DEPTH)).is_ok());
+        assert_eq!(JsonValue::parse(&nested(MAX_DEPTH + 1)), Err(MAX_DEPTH));
+        assert_eq!(JsonValue::parse(&nested(1 << 20)), Err(MAX_DEPTH));
+
+        let objects = "{\"a\": ".repeat(MAX_DEPTH + 1);
+        assert_eq!(JsonValue::parse(&objects), Err(6 * MAX_DEPTH));
+    }
+}
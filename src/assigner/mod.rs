@@ -0,0 +1,585 @@
+//! Public input format of zkLLVM assigner.
+//!
+//! Circuit inputs are a JSON array with an entry per argument of the circuit function:
+//!
+//! ```json
+//! [
+//!   {"field": 12},
+//!   {"curve": ["0x1", "0x2"]},
+//!   {"array": [{"field": 1}, {"field": "0x2"}]},
+//!   {"vector": [{"field": "3"}]}
+//! ]
+//! ```
+//!
+//! Field values are encoded as JSON numbers if they fit into `u64` and as `0x`-prefixed
+//! hexadecimal strings otherwise. Numbers, decimal and hexadecimal strings are all accepted
+//! when decoding, and every value is checked to be less than the modulus of its field.
+//! Curve elements are checked to be points of the prime-order subgroup,
+//! they are supported only with `software` backend, which exposes their coordinates.
+
+use std::error::Error;
+use std::fmt::{self, Write};
+use std::str::FromStr;
+
+use super::{
+    Bls12381Base, Bls12381Scalar, Curve25519Base, Curve25519Scalar, CurveError, PallasBase,
+    PallasScalar, ParseFieldError,
+};
+#[cfg(feature = "software")]
+use super::{Bls12381, Curve25519, Pallas, Vesta, VestaBase};
+
+mod json;
+
+pub use json::JsonValue;
+
+/// Error of decoding assigner inputs.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssignerError {
+    path: String,
+    kind: AssignerErrorKind,
+}
+
+/// Kind of [`AssignerError`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum AssignerErrorKind {
+    /// The document is not valid JSON, with byte offset of the error.
+    Syntax(usize),
+    /// The value is not of the expected kind.
+    UnexpectedValue {
+        /// Expected kind of entry or value.
+        expected: &'static str,
+    },
+    /// The value is not a valid unsigned integer.
+    InvalidNumber,
+    /// The value is not less than the field modulus.
+    OutOfRange,
+    /// Coordinates do not define a valid curve element.
+    InvalidCurve(CurveError),
+    /// Array has wrong number of items.
+    LengthMismatch {
+        /// Expected length.
+        expected: usize,
+        /// Actual length.
+        found: usize,
+    },
+    /// There are less inputs than expected.
+    MissingInput,
+    /// There are more inputs than expected.
+    TrailingInputs,
+}
+
+impl AssignerError {
+    /// Creates error at given JSON path.
+    pub fn new(path: &str, kind: AssignerErrorKind) -> Self {
+        Self {
+            path: path.to_owned(),
+            kind,
+        }
+    }
+
+    /// JSON path of the bad entry, like `$[2].array[0].field`.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Kind of the error.
+    pub fn kind(&self) -> &AssignerErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for AssignerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: ", self.path)?;
+        match &self.kind {
+            AssignerErrorKind::Syntax(offset) => write!(f, "invalid JSON at byte {}", offset),
+            AssignerErrorKind::UnexpectedValue { expected } => write!(f, "expected {}", expected),
+            AssignerErrorKind::InvalidNumber => f.write_str("invalid unsigned integer"),
+            AssignerErrorKind::OutOfRange => {
+                f.write_str("value is not less than the field modulus")
+            }
+            AssignerErrorKind::InvalidCurve(err) => err.fmt(f),
+            AssignerErrorKind::LengthMismatch { expected, found } => {
+                write!(f, "expected {} items, found {}", expected, found)
+            }
+            AssignerErrorKind::MissingInput => f.write_str("missing input"),
+            AssignerErrorKind::TrailingInputs => f.write_str("unexpected trailing inputs"),
+        }
+    }
+}
+
+impl Error for AssignerError {}
+
+/// Value passed as a single entry of assigner inputs.
+pub trait AssignerValue: Sized {
+    /// Returns the entry, e.g. `{"field": 1}`.
+    fn to_assigner_value(&self) -> JsonValue;
+
+    /// Creates value from the entry at JSON path `path`.
+    fn from_assigner_value(value: &JsonValue, path: &str) -> Result<Self, AssignerError>;
+}
+
+/// Value passed as a sequence of entries of assigner inputs.
+///
+/// Every [`AssignerValue`] is a single entry, tuples are their items in order.
+/// With `derive` feature it can be derived for structs, which are flattened the same way.
+pub trait CircuitInput: Sized {
+    /// Appends entries of the value to the inputs.
+    fn write_inputs(&self, inputs: &mut Vec<JsonValue>);
+
+    /// Creates value from the next entries of the inputs.
+    fn read_inputs(inputs: &mut InputReader<'_>) -> Result<Self, AssignerError>;
+}
+
+/// Cursor over entries of assigner inputs.
+pub struct InputReader<'a> {
+    inputs: &'a [JsonValue],
+    pos: usize,
+}
+
+impl<'a> InputReader<'a> {
+    /// Creates reader of entries.
+    pub fn new(inputs: &'a [JsonValue]) -> Self {
+        Self { inputs, pos: 0 }
+    }
+
+    /// Returns the next entry with its JSON path.
+    pub fn next_input(&mut self) -> Result<(&'a JsonValue, String), AssignerError> {
+        let path = format!("$[{}]", self.pos);
+        let value = self
+            .inputs
+            .get(self.pos)
+            .ok_or_else(|| AssignerError::new(&path, AssignerErrorKind::MissingInput))?;
+        self.pos += 1;
+        Ok((value, path))
+    }
+
+    /// Returns error if there are entries left.
+    pub fn finish(self) -> Result<(), AssignerError> {
+        if self.pos == self.inputs.len() {
+            Ok(())
+        } else {
+            let path = format!("$[{}]", self.pos);
+            Err(AssignerError::new(&path, AssignerErrorKind::TrailingInputs))
+        }
+    }
+}
+
+impl<T: AssignerValue> CircuitInput for T {
+    fn write_inputs(&self, inputs: &mut Vec<JsonValue>) {
+        inputs.push(self.to_assigner_value());
+    }
+
+    fn read_inputs(inputs: &mut InputReader<'_>) -> Result<Self, AssignerError> {
+        let (value, path) = inputs.next_input()?;
+        T::from_assigner_value(value, &path)
+    }
+}
+
+/// Implements [`CircuitInput`] for tuples.
+macro_rules! tuple_circuit_input_impl {
+    ($(($($name:ident)*))*) => ($(
+        impl<$($name: CircuitInput),*> CircuitInput for ($($name,)*) {
+            #[allow(non_snake_case, unused_variables)]
+            fn write_inputs(&self, inputs: &mut Vec<JsonValue>) {
+                let ($($name,)*) = self;
+                $($name.write_inputs(inputs);)*
+            }
+
+            #[allow(unused_variables)]
+            fn read_inputs(inputs: &mut InputReader<'_>) -> Result<Self, AssignerError> {
+                Ok(($($name::read_inputs(inputs)?,)*))
+            }
+        }
+    )*)
+}
+
+tuple_circuit_input_impl! {
+    ()
+    (A)
+    (A B)
+    (A B C)
+    (A B C D)
+    (A B C D E)
+    (A B C D E F)
+    (A B C D E F G)
+    (A B C D E F G H)
+}
+
+/// Returns assigner inputs JSON document, with an entry per line.
+pub fn to_assigner_json<T: CircuitInput>(input: &T) -> String {
+    let mut inputs = Vec::new();
+    input.write_inputs(&mut inputs);
+
+    let mut res = String::from("[");
+    for (i, entry) in inputs.iter().enumerate() {
+        let sep = if i == 0 { "" } else { "," };
+        write!(res, "{}\n  {}", sep, entry).unwrap();
+    }
+    res.push_str("\n]\n");
+    res
+}
+
+/// Parses assigner inputs JSON document.
+pub fn from_assigner_json<T: CircuitInput>(json: &str) -> Result<T, AssignerError> {
+    let document = JsonValue::parse(json)
+        .map_err(|offset| AssignerError::new("$", AssignerErrorKind::Syntax(offset)))?;
+    let JsonValue::Array(inputs) = document else {
+        return Err(AssignerError::new(
+            "$",
+            AssignerErrorKind::UnexpectedValue {
+                expected: "array of inputs",
+            },
+        ));
+    };
+
+    let mut reader = InputReader::new(&inputs);
+    let res = T::read_inputs(&mut reader)?;
+    reader.finish()?;
+    Ok(res)
+}
+
+/// Returns the value of an entry `{kind: value}`, with its JSON path.
+fn entry<'a>(
+    value: &'a JsonValue,
+    path: &str,
+    kind: &'static str,
+) -> Result<(&'a JsonValue, String), AssignerError> {
+    match value.as_singleton() {
+        Some((key, inner)) if key == kind => Ok((inner, format!("{}.{}", path, kind))),
+        _ => Err(AssignerError::new(
+            path,
+            AssignerErrorKind::UnexpectedValue { expected: kind },
+        )),
+    }
+}
+
+/// Returns items of an array.
+fn items<'a>(value: &'a JsonValue, path: &str) -> Result<&'a [JsonValue], AssignerError> {
+    match value {
+        JsonValue::Array(items) => Ok(items),
+        _ => Err(AssignerError::new(
+            path,
+            AssignerErrorKind::UnexpectedValue { expected: "array" },
+        )),
+    }
+}
+
+/// Encodes little-endian limbs of field value.
+fn encode_limbs(limbs: &[u64]) -> JsonValue {
+    if limbs[1..].iter().all(|&limb| limb == 0) {
+        return JsonValue::Number(limbs[0].to_string());
+    }
+    let mut hex = String::from("0x");
+    let mut limbs = limbs.iter().rev().skip_while(|&&limb| limb == 0);
+    write!(hex, "{:x}", limbs.next().unwrap()).unwrap();
+    for limb in limbs {
+        write!(hex, "{:016x}", limb).unwrap();
+    }
+    JsonValue::String(hex)
+}
+
+/// Decodes field value given as number or string.
+fn decode_scalar<F: FromStr<Err = ParseFieldError>>(
+    value: &JsonValue,
+    path: &str,
+) -> Result<F, AssignerError> {
+    let text = match value {
+        JsonValue::Number(text) | JsonValue::String(text) => text,
+        _ => {
+            return Err(AssignerError::new(
+                path,
+                AssignerErrorKind::UnexpectedValue {
+                    expected: "number or string",
+                },
+            ))
+        }
+    };
+    // Hexadecimal values are only allowed in strings.
+    if matches!(value, JsonValue::Number(_)) && !text.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(AssignerError::new(path, AssignerErrorKind::InvalidNumber));
+    }
+    text.parse().map_err(|err| {
+        let kind = match err {
+            ParseFieldError::OutOfRange => AssignerErrorKind::OutOfRange,
+            ParseFieldError::Empty | ParseFieldError::InvalidDigit => {
+                AssignerErrorKind::InvalidNumber
+            }
+        };
+        AssignerError::new(path, kind)
+    })
+}
+
+/// Implements [`AssignerValue`] for field wrappers.
+macro_rules! field_assigner_impl {
+    ($($t:ty)*) => ($(
+        impl AssignerValue for $t {
+            fn to_assigner_value(&self) -> JsonValue {
+                JsonValue::singleton("field", encode_limbs(&self.to_le_limbs()))
+            }
+
+            fn from_assigner_value(value: &JsonValue, path: &str) -> Result<Self, AssignerError> {
+                let (value, path) = entry(value, path, "field")?;
+                decode_scalar(value, &path)
+            }
+        }
+    )*)
+}
+
+field_assigner_impl! {
+    Bls12381Base
+    Bls12381Scalar
+    Curve25519Base
+    Curve25519Scalar
+    PallasBase
+    PallasScalar
+}
+
+/// Implements [`AssignerValue`] for curve wrappers.
+///
+/// Curve elements are encoded with affine coordinates,
+/// neutral element of a short Weierstrass curve is `(0, 0)`.
+/// Coordinates are accessible only with `software` backend.
+#[cfg(feature = "software")]
+macro_rules! curve_assigner_impl {
+    ($($curve:ty, $base:ty)*) => ($(
+        impl AssignerValue for $curve {
+            fn to_assigner_value(&self) -> JsonValue {
+                let coordinates = vec![
+                    encode_limbs(&self.0.x().to_le_limbs()),
+                    encode_limbs(&self.0.y().to_le_limbs()),
+                ];
+                JsonValue::singleton("curve", JsonValue::Array(coordinates))
+            }
+
+            fn from_assigner_value(value: &JsonValue, path: &str) -> Result<Self, AssignerError> {
+                let (value, path) = entry(value, path, "curve")?;
+                let coordinates = items(value, &path)?;
+                let [x, y] = coordinates else {
+                    let found = coordinates.len();
+                    let kind = AssignerErrorKind::LengthMismatch { expected: 2, found };
+                    return Err(AssignerError::new(&path, kind));
+                };
+                let x: $base = decode_scalar(x, &format!("{}[0]", path))?;
+                let y: $base = decode_scalar(y, &format!("{}[1]", path))?;
+
+                Self::try_from_affine(x, y)
+                    .map_err(|err| AssignerError::new(&path, AssignerErrorKind::InvalidCurve(err)))
+            }
+        }
+    )*)
+}
+
+#[cfg(feature = "software")]
+curve_assigner_impl! {
+    Bls12381, Bls12381Base
+    Curve25519, Curve25519Base
+    Pallas, PallasBase
+    Vesta, VestaBase
+}
+
+impl<T: AssignerValue, const N: usize> AssignerValue for [T; N] {
+    fn to_assigner_value(&self) -> JsonValue {
+        let items = self.iter().map(T::to_assigner_value).collect();
+        JsonValue::singleton("array", JsonValue::Array(items))
+    }
+
+    fn from_assigner_value(value: &JsonValue, path: &str) -> Result<Self, AssignerError> {
+        let (value, path) = entry(value, path, "array")?;
+        let items = items(value, &path)?;
+        if items.len() != N {
+            let kind = AssignerErrorKind::LengthMismatch {
+                expected: N,
+                found: items.len(),
+            };
+            return Err(AssignerError::new(&path, kind));
+        }
+        let values = items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| T::from_assigner_value(item, &format!("{}[{}]", path, i)))
+            .collect::<Result<Vec<_>, _>>()?;
+        // Length is checked above.
+        Ok(values.try_into().ok().unwrap())
+    }
+}
+
+impl<T: AssignerValue> AssignerValue for Vec<T> {
+    fn to_assigner_value(&self) -> JsonValue {
+        let items = self.iter().map(T::to_assigner_value).collect();
+        JsonValue::singleton("vector", JsonValue::Array(items))
+    }
+
+    fn from_assigner_value(value: &JsonValue, path: &str) -> Result<Self, AssignerError> {
+        let (value, path) = entry(value, path, "vector")?;
+        items(value, &path)?
+            .iter()
+            .enumerate()
+            .map(|(i, item)| T::from_assigner_value(item, &format!("{}[{}]", path, i)))
+            .collect()
+    }
+}
+
+#[cfg(all(test, feature = "software"))]
+mod tests {
+    use super::*;
+
+    // Pallas base field modulus minus one and modulus.
+    const MAX_DEC: &str =
+        "28948022309329048855892746252171976963363056481941560715954676764349967630336";
+    const MODULUS_DEC: &str =
+        "28948022309329048855892746252171976963363056481941560715954676764349967630337";
+    const MAX_HEX: &str = "0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000";
+    const MODULUS_HEX: &str = "0x40000000000000000000000000000000224698fc094cf91b992d30ed00000001";
+
+    fn max() -> PallasBase {
+        -PallasBase::from_le_limbs([1, 0, 0, 0]).unwrap()
+    }
+
+    fn decode(json: &str) -> Result<PallasBase, AssignerError> {
+        from_assigner_json(&format!("[{{\"field\": {}}}]", json))
+    }
+
+    fn error_kind<T: CircuitInput>(json: &str) -> AssignerErrorKind {
+        from_assigner_json::<T>(json).err().unwrap().kind().clone()
+    }
+
+    #[test]
+    fn field_encoding() {
+        let x = PallasBase::from_le_limbs([12, 0, 0, 0]).unwrap();
+        assert_eq!(to_assigner_json(&x), "[\n  {\"field\": 12}\n]\n");
+        assert_eq!(
+            to_assigner_json(&max()),
+            format!("[\n  {{\"field\": \"{}\"}}\n]\n", MAX_HEX)
+        );
+    }
+
+    #[test]
+    fn field_values_at_modulus() {
+        assert_eq!(
+            decode("12"),
+            Ok(PallasBase::from_le_limbs([12, 0, 0, 0]).unwrap())
+        );
+        assert_eq!(decode("\"0xc\""), decode("\"12\""));
+        assert_eq!(decode(MAX_DEC), Ok(max()));
+        assert_eq!(decode(&format!("\"{}\"", MAX_DEC)), Ok(max()));
+        assert_eq!(decode(&format!("\"{}\"", MAX_HEX)), Ok(max()));
+
+        let out_of_range = |json: &str| decode(json).err().unwrap().kind().clone();
+        assert_eq!(out_of_range(MODULUS_DEC), AssignerErrorKind::OutOfRange);
+        assert_eq!(
+            out_of_range(&format!("\"{}\"", MODULUS_DEC)),
+            AssignerErrorKind::OutOfRange
+        );
+        assert_eq!(
+            out_of_range(&format!("\"{}\"", MODULUS_HEX)),
+            AssignerErrorKind::OutOfRange
+        );
+        assert_eq!(
+            out_of_range(&format!("\"{}0\"", MAX_HEX)),
+            AssignerErrorKind::OutOfRange
+        );
+    }
+
+    #[test]
+    fn invalid_field_values() {
+        for json in ["-1", "1.0", "1e3", "\"\"", "\"0x\"", "\"-1\"", "\"12a\""] {
+            assert_eq!(
+                decode(json).err().unwrap().kind(),
+                &AssignerErrorKind::InvalidNumber
+            );
+        }
+        let err = decode("[1]").err().unwrap();
+        assert_eq!(err.path(), "$[0].field");
+        assert_eq!(
+            err.kind(),
+            &AssignerErrorKind::UnexpectedValue {
+                expected: "number or string"
+            }
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let one = PallasBase::from_le_limbs([1, 0, 0, 0]).unwrap();
+        let input = (
+            [one, max()],
+            vec![max(), one, one],
+            Pallas::one() + Pallas::one(),
+            Vesta::zero(),
+        );
+        let json = to_assigner_json(&input);
+        assert_eq!(from_assigner_json(&json), Ok(input));
+
+        let curve25519 = Curve25519::one();
+        assert_eq!(
+            from_assigner_json(&to_assigner_json(&curve25519)),
+            Ok(curve25519)
+        );
+    }
+
+    #[test]
+    fn document_errors() {
+        assert_eq!(
+            error_kind::<PallasBase>("[{\"field\": 1"),
+            AssignerErrorKind::Syntax(12)
+        );
+        assert_eq!(
+            error_kind::<PallasBase>("{\"field\": 1}"),
+            AssignerErrorKind::UnexpectedValue {
+                expected: "array of inputs"
+            }
+        );
+        assert_eq!(
+            error_kind::<PallasBase>("[]"),
+            AssignerErrorKind::MissingInput
+        );
+        assert_eq!(
+            error_kind::<PallasBase>("[{\"field\": 1}, {\"field\": 2}]"),
+            AssignerErrorKind::TrailingInputs
+        );
+
+        let deep = "[".repeat(1 << 16) + &"]".repeat(1 << 16);
+        assert!(matches!(
+            error_kind::<()>(&deep),
+            AssignerErrorKind::Syntax(_)
+        ));
+    }
+
+    #[test]
+    fn collection_errors() {
+        let err = from_assigner_json::<[PallasBase; 2]>("[{\"array\": [{\"field\": 1}]}]")
+            .err()
+            .unwrap();
+        assert_eq!(err.path(), "$[0].array");
+        assert_eq!(
+            err.kind(),
+            &AssignerErrorKind::LengthMismatch {
+                expected: 2,
+                found: 1
+            }
+        );
+
+        let err = from_assigner_json::<Vec<PallasBase>>("[{\"vector\": [{\"field\": 1}, 2]}]")
+            .err()
+            .unwrap();
+        assert_eq!(err.path(), "$[0].vector[1]");
+    }
+
+    #[test]
+    fn curve_errors() {
+        let json = "[{\"curve\": [\"0x1\", \"0x2\"]}]";
+        assert_eq!(
+            error_kind::<Pallas>(json),
+            AssignerErrorKind::InvalidCurve(CurveError::NotOnCurve)
+        );
+        assert_eq!(
+            error_kind::<Pallas>("[{\"curve\": [1]}]"),
+            AssignerErrorKind::LengthMismatch {
+                expected: 2,
+                found: 1
+            }
+        );
+    }
+}
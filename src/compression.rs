@@ -0,0 +1,170 @@
+//! Compressed encodings of curve elements.
+//!
+//! A point is encoded with its `x` coordinate and a single bit choosing one of two `y`
+//! (or `y` and a bit choosing `x` for Edwards curves), which is recovered with square root.
+//!
+//! * `Pallas` and `Vesta` use the encoding of `pasta_curves`: little-endian `x`
+//!   with the parity of `y` in the most significant bit, neutral element is all zeros.
+//! * `Bls12381` uses the encoding of `zcash`: big-endian `x` with three most significant
+//!   bits being compression, infinity and sort (`y` is lexicographically largest) flags.
+//! * `Curve25519` uses the encoding of RFC 8032: little-endian `y`
+//!   with the parity of `x` in the most significant bit.
+//!
+//! Compression needs affine coordinates, which `rslang` builtins do not expose,
+//! so both directions are available only with `software` backend.
+
+use super::{
+    Bls12381, Bls12381Base, Curve25519, Curve25519Base, CurveError, Pallas, PallasBase,
+    PallasScalar, Vesta,
+};
+
+/// Implements compression with `pasta_curves` encoding.
+macro_rules! pasta_compression_impl {
+    ($($curve:ty, $base:ident)*) => ($(
+        impl $curve {
+            /// Returns compressed encoding of the curve element.
+            pub fn to_compressed(&self) -> [u8; 32] {
+                if self.is_zero() {
+                    return [0u8; 32];
+                }
+
+                let (x, y) = ($base(self.0.x()), $base(self.0.y()));
+                let mut bytes = x.to_bytes_le();
+                bytes[31] |= (y.to_bytes_le()[0] & 1) << 7;
+                bytes
+            }
+
+            /// Creates curve element from compressed encoding.
+            pub fn from_compressed(bytes: &[u8; 32]) -> Result<Self, CurveError> {
+                let sign = bytes[31] >> 7;
+                let mut repr = *bytes;
+                repr[31] &= 0x7f;
+                let x = <$base>::from_bytes_le(&repr).ok_or(CurveError::InvalidEncoding)?;
+
+                let zero = $base(builtin_zero!($base));
+                if x == zero && sign == 0 {
+                    return Ok(Self::zero());
+                }
+
+                let y = (x * x * x + Self::COEFF_B).sqrt().ok_or(CurveError::NotOnCurve)?;
+                let y = if y.to_bytes_le()[0] & 1 == sign { y } else { -y };
+                Self::try_from_coordinates(x, y)
+            }
+        }
+    )*)
+}
+
+pasta_compression_impl! {
+    Pallas, PallasBase
+    Vesta, PallasScalar
+}
+
+impl Bls12381 {
+    const COMPRESSION_FLAG: u8 = 1 << 7;
+    const INFINITY_FLAG: u8 = 1 << 6;
+    const SORT_FLAG: u8 = 1 << 5;
+
+    /// Returns compressed encoding of the curve element.
+    pub fn to_compressed(&self) -> [u8; 48] {
+        if self.is_zero() {
+            let mut bytes = [0u8; 48];
+            bytes[0] = Self::COMPRESSION_FLAG | Self::INFINITY_FLAG;
+            return bytes;
+        }
+
+        let (x, y) = (Bls12381Base(self.0.x()), Bls12381Base(self.0.y()));
+        let mut bytes = x.to_bytes_be();
+        bytes[0] |= Self::COMPRESSION_FLAG;
+        if y > -y {
+            bytes[0] |= Self::SORT_FLAG;
+        }
+        bytes
+    }
+
+    /// Creates curve element from compressed encoding.
+    ///
+    /// Points outside of the prime-order subgroup are rejected.
+    pub fn from_compressed(bytes: &[u8; 48]) -> Result<Self, CurveError> {
+        let flags = bytes[0];
+        if flags & Self::COMPRESSION_FLAG == 0 {
+            return Err(CurveError::InvalidEncoding);
+        }
+
+        let mut repr = *bytes;
+        repr[0] &= 0x1f;
+        if flags & Self::INFINITY_FLAG != 0 {
+            // Neutral element has no sort flag and zero `x`.
+            return if flags & Self::SORT_FLAG == 0 && repr.iter().all(|&b| b == 0) {
+                Ok(Self::zero())
+            } else {
+                Err(CurveError::InvalidEncoding)
+            };
+        }
+
+        repr.reverse();
+        let x = Bls12381Base::from_bytes_le(&repr).ok_or(CurveError::InvalidEncoding)?;
+        let y = (x * x * x + Self::COEFF_B).sqrt().ok_or(CurveError::NotOnCurve)?;
+        let y = if (y > -y) == (flags & Self::SORT_FLAG != 0) { y } else { -y };
+        Self::try_from_coordinates(x, y)
+    }
+}
+
+impl Curve25519 {
+    /// Returns compressed encoding of the curve element.
+    pub fn to_compressed(&self) -> [u8; 32] {
+        let (x, y) = (Curve25519Base(self.0.x()), Curve25519Base(self.0.y()));
+        let mut bytes = y.to_bytes_le();
+        bytes[31] |= (x.to_bytes_le()[0] & 1) << 7;
+        bytes
+    }
+
+    /// Creates curve element from compressed encoding.
+    ///
+    /// Unlike RFC 8032, points outside of the prime-order subgroup are rejected.
+    pub fn from_compressed(bytes: &[u8; 32]) -> Result<Self, CurveError> {
+        let sign = bytes[31] >> 7;
+        let mut repr = *bytes;
+        repr[31] &= 0x7f;
+        let y = Curve25519Base::from_bytes_le(&repr).ok_or(CurveError::InvalidEncoding)?;
+
+        // `x^2 = (y^2 - 1) / (d * y^2 - a)`, the denominator is never zero.
+        let one = Curve25519Base(builtin_one!(Curve25519Base));
+        let y2 = y * y;
+        let x2 = (y2 - one) / (Self::COEFF_D * y2 - Self::COEFF_A);
+        let x = x2.sqrt().ok_or(CurveError::NotOnCurve)?;
+
+        let zero = Curve25519Base(builtin_zero!(Curve25519Base));
+        if x == zero && sign == 1 {
+            return Err(CurveError::InvalidEncoding);
+        }
+        let x = if x.to_bytes_le()[0] & 1 == sign { x } else { -x };
+        Self::try_from_coordinates(x, y)
+    }
+}
+
+#[cfg(all(test, feature = "software"))]
+mod tests {
+    use super::*;
+
+    macro_rules! round_trip_tests {
+        ($($name:ident, $curve:ty;)*) => ($(
+            #[test]
+            fn $name() {
+                let g = <$curve>::one();
+                let mut p = <$curve>::zero();
+                for _ in 0..8 {
+                    assert_eq!(<$curve>::from_compressed(&p.to_compressed()), Ok(p));
+                    assert_eq!(<$curve>::from_compressed(&(-p).to_compressed()), Ok(-p));
+                    p += g;
+                }
+            }
+        )*)
+    }
+
+    round_trip_tests! {
+        bls12381_round_trip, Bls12381;
+        curve25519_round_trip, Curve25519;
+        pallas_round_trip, Pallas;
+        vesta_round_trip, Vesta;
+    }
+}
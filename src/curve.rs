@@ -0,0 +1,179 @@
+//! Parameters of elliptic curves.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use super::{
+    Bls12381, Bls12381Base, Bls12381Scalar, Curve25519, Curve25519Base, Curve25519Scalar, Pallas,
+    PallasBase, PallasScalar, Vesta, VestaScalar, ZkField,
+};
+
+/// Elliptic curve group with its base and scalar fields.
+///
+/// Coefficients `a` and `b` are the ones of short Weierstrass equation `y^2 = x^3 + a * x + b`,
+/// or of twisted Edwards equation `a * x^2 + y^2 = 1 + b * x^2 * y^2` (where `b` is usually
+/// denoted `d`) for `Curve25519`.
+pub trait ZkCurve:
+    Copy
+    + Default
+    + Eq
+    + fmt::Debug
+    + fmt::Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Neg<Output = Self>
+    + Mul<Self::Scalar, Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign<Self::Scalar>
+{
+    /// Field of point coordinates.
+    type Base: ZkField;
+
+    /// Field of scalars, which order is the order of the prime-order subgroup.
+    type Scalar: ZkField;
+
+    /// Curve coefficient `a`.
+    const COEFF_A: Self::Base;
+
+    /// Curve coefficient `b`.
+    const COEFF_B: Self::Base;
+
+    /// Cofactor of the prime-order subgroup as little-endian limbs.
+    const COFACTOR: &'static [u64];
+
+    /// Order of the prime-order subgroup as little-endian limbs.
+    const ORDER: &'static [u64] = <Self::Scalar as ZkField>::MODULUS;
+
+    /// Affine `x` coordinate of the generator.
+    const GENERATOR_X: Self::Base;
+
+    /// Affine `y` coordinate of the generator.
+    const GENERATOR_Y: Self::Base;
+
+    /// Create neutral curve element.
+    fn zero() -> Self;
+
+    /// Create generator (`one`) curve element.
+    fn one() -> Self;
+
+    /// Create curve element from base field coordinates.
+    ///
+    /// # Safety
+    ///
+    /// Coordinates are not checked to be a point on the curve.
+    unsafe fn from_coordinates(x: Self::Base, y: Self::Base) -> Self;
+}
+
+/// Implements coefficients of curves in short Weierstrass form `y^2 = x^3 + b`.
+macro_rules! weierstrass_coeffs_impl {
+    ($($curve:ty, $base:ident, $b:tt, $b_limbs:expr;)*) => ($(
+        impl $curve {
+            /// Curve coefficient `b`.
+            pub(crate) const COEFF_B: $base = field_const!($base, $b, $b_limbs);
+        }
+    )*)
+}
+
+weierstrass_coeffs_impl! {
+    Bls12381, Bls12381Base, 4g, [0x4, 0x0, 0x0, 0x0, 0x0, 0x0];
+    Pallas, PallasBase, 5g, [0x5, 0x0, 0x0, 0x0];
+    Vesta, PallasScalar, 5g, [0x5, 0x0, 0x0, 0x0];
+}
+
+/// Implements coefficients of curves in twisted Edwards form
+/// `a * x^2 + y^2 = 1 + d * x^2 * y^2`.
+macro_rules! edwards_coeffs_impl {
+    ($($curve:ty, $base:ident, $a:tt, $a_limbs:expr, $d:tt, $d_limbs:expr;)*) => ($(
+        impl $curve {
+            /// Curve coefficient `a`.
+            pub(crate) const COEFF_A: $base = field_const!($base, $a, $a_limbs);
+
+            /// Curve coefficient `d`.
+            pub(crate) const COEFF_D: $base = field_const!($base, $d, $d_limbs);
+        }
+    )*)
+}
+
+edwards_coeffs_impl! {
+    Curve25519, Curve25519Base,
+    57896044618658097711785492504343953926634992332820282019728792003956564819948g,
+    Curve25519Base::MODULUS_MINUS_ONE,
+    37095705934669439343138083508754565189542113879843219016388785533085940283555g,
+    [0x75eb4dca135978a3, 0x00700a4d4141d8ab, 0x8cc740797779e898, 0x52036cee2b6ffe73];
+}
+
+/// Implements [`ZkCurve`].
+macro_rules! zk_curve_impl {
+    ($($curve:ty, $base:ident, $scalar:ty, $a:expr, $b:expr, $cofactor:expr,
+       $gx:tt, $gx_limbs:expr, $gy:tt, $gy_limbs:expr;)*) => ($(
+        impl ZkCurve for $curve {
+            type Base = $base;
+
+            type Scalar = $scalar;
+
+            const COEFF_A: $base = $a;
+
+            const COEFF_B: $base = $b;
+
+            const COFACTOR: &'static [u64] = &$cofactor;
+
+            const GENERATOR_X: $base = field_const!($base, $gx, $gx_limbs);
+
+            const GENERATOR_Y: $base = field_const!($base, $gy, $gy_limbs);
+
+            #[inline(always)]
+            fn zero() -> Self {
+                <$curve>::zero()
+            }
+
+            #[inline(always)]
+            fn one() -> Self {
+                <$curve>::one()
+            }
+
+            #[inline(always)]
+            unsafe fn from_coordinates(x: $base, y: $base) -> Self {
+                <$curve>::from_coordinates(x, y)
+            }
+        }
+    )*)
+}
+
+zk_curve_impl! {
+    Bls12381, Bls12381Base, Bls12381Scalar,
+    Bls12381Base(builtin_zero!(Bls12381Base)), Bls12381::COEFF_B,
+    [0x8c00aaab0000aaab, 0x396c8c005555e156],
+    3685416753713387016781088315183077757961620795782546409894578378688607592378376318836054947676345821548104185464507g,
+    [
+        0xfb3af00adb22c6bb, 0x6c55e83ff97a1aef, 0xa14e3a3f171bac58,
+        0xc3688c4f9774b905, 0x2695638c4fa9ac0f, 0x17f1d3a73197d794,
+    ],
+    1339506544944476473020471379941921221584933875938349620426543736416511423956333506472724655353366534992391756441569g,
+    [
+        0x0caa232946c5e7e1, 0xd03cc744a2888ae4, 0x00db18cb2c04b3ed,
+        0xfcf5e095d5d00af6, 0xa09e30ed741d8ae4, 0x08b3f481e3aaa0f1,
+    ];
+
+    Curve25519, Curve25519Base, Curve25519Scalar,
+    Curve25519::COEFF_A, Curve25519::COEFF_D,
+    [8],
+    15112221349535400772501151409588531511454012693041857206046113283949847762202g,
+    [0xc9562d608f25d51a, 0x692cc7609525a7b2, 0xc0a4e231fdd6dc5c, 0x216936d3cd6e53fe],
+    46316835694926478169428394003475163141307993866256225615783033603165251855960g,
+    [0x6666666666666658, 0x6666666666666666, 0x6666666666666666, 0x6666666666666666];
+
+    Pallas, PallasBase, PallasScalar,
+    PallasBase(builtin_zero!(PallasBase)), Pallas::COEFF_B,
+    [1],
+    28948022309329048855892746252171976963363056481941560715954676764349967630336g,
+    PallasBase::MODULUS_MINUS_ONE,
+    2g, [0x2, 0x0, 0x0, 0x0];
+
+    Vesta, PallasScalar, VestaScalar,
+    PallasScalar(builtin_zero!(PallasScalar)), Vesta::COEFF_B,
+    [1],
+    28948022309329048855892746252171976963363056481941647379679742748393362948096g,
+    PallasScalar::MODULUS_MINUS_ONE,
+    2g, [0x2, 0x0, 0x0, 0x0];
+}
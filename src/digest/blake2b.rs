@@ -0,0 +1,161 @@
+//! BLAKE2b hash function as specified in RFC 7693.
+
+const IV: [u64; 8] = [
+    0x6a09e667f3bcc908, 0xbb67ae8584caa73b, 0x3c6ef372fe94f82b, 0xa54ff53a5f1d36f1,
+    0x510e527fade682d1, 0x9b05688c2b3e6c1f, 0x1f83d9abfb41bd6b, 0x5be0cd19137e2179,
+];
+
+const SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+/// Unkeyed BLAKE2b with 64-byte output.
+#[derive(Clone)]
+pub(crate) struct Blake2b512 {
+    h: [u64; 8],
+    buf: [u8; Self::BLOCK_SIZE],
+    buf_len: usize,
+    counter: u128,
+}
+
+impl Blake2b512 {
+    /// Size of the digest in bytes.
+    pub(crate) const OUTPUT_SIZE: usize = 64;
+
+    /// Size of the input block in bytes.
+    pub(crate) const BLOCK_SIZE: usize = 128;
+
+    pub(crate) fn new() -> Self {
+        let mut h = IV;
+        // Parameter block with digest length set and no key, salt or personalization.
+        h[0] ^= 0x01010000 ^ Self::OUTPUT_SIZE as u64;
+        Self { h, buf: [0u8; Self::BLOCK_SIZE], buf_len: 0, counter: 0 }
+    }
+
+    pub(crate) fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            // The last block is compressed in `finalize`, so a full buffer is kept
+            // until more data arrives.
+            if self.buf_len == Self::BLOCK_SIZE {
+                self.counter += Self::BLOCK_SIZE as u128;
+                let block = self.buf;
+                self.compress(&block, false);
+                self.buf_len = 0;
+            }
+            let n = data.len().min(Self::BLOCK_SIZE - self.buf_len);
+            self.buf[self.buf_len..self.buf_len + n].copy_from_slice(&data[..n]);
+            self.buf_len += n;
+            data = &data[n..];
+        }
+    }
+
+    pub(crate) fn finalize(mut self) -> [u8; 64] {
+        self.counter += self.buf_len as u128;
+        self.buf[self.buf_len..].fill(0);
+        let block = self.buf;
+        self.compress(&block, true);
+
+        let mut out = [0u8; 64];
+        for (chunk, word) in out.chunks_mut(8).zip(self.h) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        out
+    }
+
+    fn compress(&mut self, block: &[u8; Self::BLOCK_SIZE], last: bool) {
+        let mut m = [0u64; 16];
+        for (word, chunk) in m.iter_mut().zip(block.chunks(8)) {
+            *word = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+
+        let mut v = [0u64; 16];
+        v[..8].copy_from_slice(&self.h);
+        v[8..].copy_from_slice(&IV);
+        v[12] ^= self.counter as u64;
+        v[13] ^= (self.counter >> 64) as u64;
+        if last {
+            v[14] = !v[14];
+        }
+
+        for s in SIGMA.iter().cycle().take(12) {
+            g(&mut v, 0, 4, 8, 12, m[s[0]], m[s[1]]);
+            g(&mut v, 1, 5, 9, 13, m[s[2]], m[s[3]]);
+            g(&mut v, 2, 6, 10, 14, m[s[4]], m[s[5]]);
+            g(&mut v, 3, 7, 11, 15, m[s[6]], m[s[7]]);
+            g(&mut v, 0, 5, 10, 15, m[s[8]], m[s[9]]);
+            g(&mut v, 1, 6, 11, 12, m[s[10]], m[s[11]]);
+            g(&mut v, 2, 7, 8, 13, m[s[12]], m[s[13]]);
+            g(&mut v, 3, 4, 9, 14, m[s[14]], m[s[15]]);
+        }
+
+        for i in 0..8 {
+            self.h[i] ^= v[i] ^ v[i + 8];
+        }
+    }
+}
+
+/// Mixing function `G`.
+fn g(v: &mut [u64; 16], a: usize, b: usize, c: usize, d: usize, x: u64, y: u64) {
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+    v[d] = (v[d] ^ v[a]).rotate_right(32);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(24);
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(63);
+}
+
+#[cfg(all(test, feature = "software"))]
+mod tests {
+    use super::*;
+    use crate::digest::to_hex;
+
+    /// Hashes `msg` at once and in small chunks and compares both digests with `expected`.
+    fn check(msg: &[u8], expected: &str) {
+        let mut hasher = Blake2b512::new();
+        hasher.update(msg);
+        assert_eq!(to_hex(&hasher.finalize()), expected);
+
+        let mut hasher = Blake2b512::new();
+        for chunk in msg.chunks(7) {
+            hasher.update(chunk);
+        }
+        assert_eq!(to_hex(&hasher.finalize()), expected);
+    }
+
+    // RFC 7693, Appendix A, and the empty message.
+    #[test]
+    fn known_answers() {
+        check(
+            b"",
+            "786a02f742015903c6c6fd852552d272912f4740e15847618a86e217f71f5419d25e1031afee585313896444934eb04b903a685b1448b755d56f701afe9be2ce",
+        );
+        check(
+            b"abc",
+            "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d17d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923",
+        );
+    }
+
+    // A full last block must be compressed only in `finalize`.
+    #[test]
+    fn block_boundaries() {
+        check(
+            &[b'a'; 128],
+            "fc6c71f688f43ea7d60817478808f3cac753e61571865c95adbc2d9122c943a76b92c2cb1047ef3fe7bf6e436ec1d0a99a9e5b216780bf7fed9d7ca91d3a8f3b",
+        );
+        check(
+            &[b'a'; 129],
+            "55e6e0eb418149a8af92fd9ddc99254781b2f522a131b4f4d984404b71a00e1167b8124d5dcddd4c6977b299392335d6edd303da6d344d74bbef2d38101b232b",
+        );
+    }
+}
@@ -0,0 +1,21 @@
+//! Hash functions used by hash-to-field and hash-to-curve.
+//!
+//! These are small straightforward implementations of the standard algorithms,
+//! so that they can be compiled into circuits without external dependencies.
+
+// BLAKE2b is used only by hash-to-curve for Pallas and Vesta.
+#[cfg(feature = "hash-to-curve")]
+mod blake2b;
+mod sha256;
+mod shake;
+
+#[cfg(feature = "hash-to-curve")]
+pub(crate) use blake2b::Blake2b512;
+pub(crate) use sha256::Sha256;
+pub(crate) use shake::Shake128;
+
+/// Returns lowercase hexadecimal encoding of `bytes`.
+#[cfg(all(test, feature = "software"))]
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
@@ -0,0 +1,154 @@
+//! SHAKE128 extendable-output function as specified in FIPS 202.
+
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001, 0x0000000000008082, 0x800000000000808a, 0x8000000080008000,
+    0x000000000000808b, 0x0000000080000001, 0x8000000080008081, 0x8000000000008009,
+    0x000000000000008a, 0x0000000000000088, 0x0000000080008009, 0x000000008000000a,
+    0x000000008000808b, 0x800000000000008b, 0x8000000000008089, 0x8000000000008003,
+    0x8000000000008002, 0x8000000000000080, 0x000000000000800a, 0x800000008000000a,
+    0x8000000080008081, 0x8000000000008080, 0x0000000080000001, 0x8000000080008008,
+];
+
+/// Rotation offsets, indexed by lane `x + 5 * y`.
+const ROTATIONS: [u32; 25] = [
+    0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14,
+];
+
+/// Keccak-f\[1600\] permutation.
+fn keccak_f(a: &mut [u64; 25]) {
+    for rc in ROUND_CONSTANTS {
+        // θ step.
+        let mut c = [0u64; 5];
+        for x in 0..5 {
+            c[x] = a[x] ^ a[x + 5] ^ a[x + 10] ^ a[x + 15] ^ a[x + 20];
+        }
+        for x in 0..5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            for y in 0..5 {
+                a[x + 5 * y] ^= d;
+            }
+        }
+
+        // ρ and π steps.
+        let mut b = [0u64; 25];
+        for x in 0..5 {
+            for y in 0..5 {
+                b[y + 5 * ((2 * x + 3 * y) % 5)] = a[x + 5 * y].rotate_left(ROTATIONS[x + 5 * y]);
+            }
+        }
+
+        // χ step.
+        for x in 0..5 {
+            for y in 0..5 {
+                a[x + 5 * y] = b[x + 5 * y] ^ (!b[(x + 1) % 5 + 5 * y] & b[(x + 2) % 5 + 5 * y]);
+            }
+        }
+
+        // ι step.
+        a[0] ^= rc;
+    }
+}
+
+/// SHAKE128 hasher.
+#[derive(Clone)]
+pub(crate) struct Shake128 {
+    state: [u64; 25],
+    buf: [u8; Self::RATE],
+    buf_len: usize,
+}
+
+impl Shake128 {
+    /// Sponge rate in bytes.
+    const RATE: usize = 168;
+
+    pub(crate) fn new() -> Self {
+        Self { state: [0u64; 25], buf: [0u8; Self::RATE], buf_len: 0 }
+    }
+
+    pub(crate) fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            let n = data.len().min(Self::RATE - self.buf_len);
+            self.buf[self.buf_len..self.buf_len + n].copy_from_slice(&data[..n]);
+            self.buf_len += n;
+            data = &data[n..];
+            if self.buf_len == Self::RATE {
+                self.absorb_block();
+            }
+        }
+    }
+
+    /// Returns `len` bytes of output.
+    pub(crate) fn finalize_xof(mut self, len: usize) -> Vec<u8> {
+        // Domain separation bits `1111` followed by `pad10*1`.
+        self.buf[self.buf_len..].fill(0);
+        self.buf[self.buf_len] ^= 0x1f;
+        self.buf[Self::RATE - 1] ^= 0x80;
+        self.absorb_block();
+
+        let mut out = Vec::with_capacity(len);
+        loop {
+            for lane in &self.state[..Self::RATE / 8] {
+                for byte in lane.to_le_bytes() {
+                    if out.len() == len {
+                        return out;
+                    }
+                    out.push(byte);
+                }
+            }
+            keccak_f(&mut self.state);
+        }
+    }
+
+    fn absorb_block(&mut self) {
+        for (lane, chunk) in self.state.iter_mut().zip(self.buf.chunks(8)) {
+            *lane ^= u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+        keccak_f(&mut self.state);
+        self.buf_len = 0;
+    }
+}
+
+#[cfg(all(test, feature = "software"))]
+mod tests {
+    use super::*;
+    use crate::digest::to_hex;
+
+    /// Hashes `msg` at once and in small chunks and compares both outputs with `expected`.
+    fn check(msg: &[u8], expected: &str) {
+        let len = expected.len() / 2;
+
+        let mut hasher = Shake128::new();
+        hasher.update(msg);
+        assert_eq!(to_hex(&hasher.finalize_xof(len)), expected);
+
+        let mut hasher = Shake128::new();
+        for chunk in msg.chunks(7) {
+            hasher.update(chunk);
+        }
+        assert_eq!(to_hex(&hasher.finalize_xof(len)), expected);
+    }
+
+    // FIPS 202 examples.
+    #[test]
+    fn known_answers() {
+        check(b"", "7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26");
+        check(b"abc", "5881092dd818bf5cf8a3ddb793fbcba74097d5c526a6d35f97b83351940f2cc8");
+        check(&[0xa3; 200], "131ab8d2b594946b9c81333f9bb6e0ce75c3b93104fa3469d3917457385da037");
+    }
+
+    // Inputs around the sponge rate.
+    #[test]
+    fn rate_boundaries() {
+        check(&[b'a'; 167], "4f5c6c53ae8190a8ff8a55b2125d28703052d10278570960c2066a905d916c34");
+        check(&[b'a'; 168], "c22e11586c22b713bde373fce93314d76829de2c21d940a28eb659b8dec953a2");
+    }
+
+    // Output longer than the sponge rate needs more than one squeeze.
+    #[test]
+    fn long_output() {
+        check(
+            b"",
+            "7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef263cb1eea988004b93103cfb0aeefd2a686e01fa4a58e8a3639ca8a1e3f9ae57e235b8cc873c23dc62b8d260169afa2f75ab916a58d974918835d25e6a435085b2badfd6dfaac359a5efbb7bcc4b59d538df9a04302e10c8bc1cbf1a0b3a5120ea17cda7cfad765f5623474d368ccca8af0007cd9f5e4c849f167a580b14aabdefaee7eef47cb0fca9767be1fda69419dfb927e9df07348b196691abaeb580b32def58538b8d23f877",
+        );
+    }
+}
@@ -0,0 +1,306 @@
+//! Parameters of prime fields.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use super::{
+    Bls12381Base, Bls12381Scalar, Curve25519Base, Curve25519Scalar, PallasBase, PallasScalar,
+};
+
+/// Prime field with its parameters.
+pub trait ZkField:
+    Copy
+    + Default
+    + Eq
+    + Ord
+    + fmt::Debug
+    + fmt::Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    /// Field modulus as little-endian limbs.
+    const MODULUS: &'static [u64];
+
+    /// Field modulus as decimal string.
+    const MODULUS_STR: &'static str;
+
+    /// Bit size of the field modulus.
+    const NUM_BITS: u32;
+
+    /// Number of bits which can be stored in a field element without wrapping around,
+    /// `NUM_BITS - 1`.
+    const CAPACITY: u32;
+
+    /// Two-adicity `s` of `p - 1 = 2^s * t` with odd `t`.
+    const TWO_ADICITY: u32;
+
+    /// Generator of the multiplicative group of the field.
+    const MULTIPLICATIVE_GENERATOR: Self;
+
+    /// Primitive `2^s`-th root of unity, `MULTIPLICATIVE_GENERATOR^t`.
+    const ROOT_OF_UNITY: Self;
+}
+
+/// Returns the number of significant bits of `a`.
+const fn num_bits<const N: usize>(a: &[u64; N]) -> u32 {
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        if a[i] != 0 {
+            return 64 * i as u32 + 64 - a[i].leading_zeros();
+        }
+    }
+    0
+}
+
+/// Returns the number of trailing zero bits of nonzero `a`.
+const fn trailing_zeros<const N: usize>(a: &[u64; N]) -> u32 {
+    let mut i = 0;
+    while a[i] == 0 {
+        i += 1;
+    }
+    64 * i as u32 + a[i].trailing_zeros()
+}
+
+/// Computes `a >> shift`.
+#[cfg(feature = "sqrt")]
+const fn shr<const N: usize>(a: &[u64; N], shift: u32) -> [u64; N] {
+    let (limbs, bits) = ((shift / 64) as usize, shift % 64);
+    let mut r = [0u64; N];
+    let mut i = 0;
+    while i + limbs < N {
+        r[i] = a[i + limbs] >> bits;
+        if bits != 0 && i + limbs + 1 < N {
+            r[i] |= a[i + limbs + 1] << (64 - bits);
+        }
+        i += 1;
+    }
+    r
+}
+
+/// Returns decimal digits of `a`, right-aligned in the buffer, and the index of the first one.
+const fn to_decimal<const N: usize>(a: &[u64; N]) -> ([u8; 128], usize) {
+    let mut a = *a;
+    let mut buf = [0u8; 128];
+    let mut start = buf.len();
+    loop {
+        let mut rem = 0u128;
+        let mut i = N;
+        while i > 0 {
+            i -= 1;
+            let acc = (rem << 64) | a[i] as u128;
+            a[i] = (acc / 10) as u64;
+            rem = acc % 10;
+        }
+        start -= 1;
+        buf[start] = b'0' + rem as u8;
+        if num_bits(&a) == 0 {
+            return (buf, start);
+        }
+    }
+}
+
+/// Returns the digits of `buf` from `start`, i.e. `&buf[start..]`,
+/// which is not available in constant context.
+const fn skip_digits(buf: &[u8], start: usize) -> &[u8] {
+    let mut digits = buf;
+    let mut i = 0;
+    while i < start {
+        if let [_, rest @ ..] = digits {
+            digits = rest;
+        }
+        i += 1;
+    }
+    digits
+}
+
+/// Implements field parameters derived from the modulus, and [`ZkField`].
+///
+/// This is the only place where field moduli are defined,
+/// the rest of the crate (including `software` backend) refers to the constants below.
+macro_rules! zk_field_impl {
+    ($($t:ident, $limbs:literal, $modulus:expr,
+       $generator:tt, $generator_limbs:expr, $root:tt, $root_limbs:expr;)*) => ($(
+        impl $t {
+            /// Field modulus as little-endian limbs.
+            pub(crate) const MODULUS: [u64; $limbs] = $modulus;
+
+            /// Bit size of the field modulus.
+            pub(crate) const NUM_BITS: u32 = num_bits(&Self::MODULUS);
+
+            /// `p - 1` as little-endian limbs, the canonical value of `-1`.
+            pub(crate) const MODULUS_MINUS_ONE: [u64; $limbs] = {
+                // The modulus is odd, so only the lowest bit changes.
+                let mut limbs = Self::MODULUS;
+                limbs[0] -= 1;
+                limbs
+            };
+
+            /// `(p - 1) / 2` as little-endian limbs.
+            #[cfg(feature = "sqrt")]
+            pub(crate) const MODULUS_MINUS_ONE_DIV_TWO: [u64; $limbs] =
+                shr(&Self::MODULUS_MINUS_ONE, 1);
+
+            /// Two-adicity `s` of `p - 1 = 2^s * t`.
+            pub(crate) const TWO_ADICITY: u32 = trailing_zeros(&Self::MODULUS_MINUS_ONE);
+
+            /// `(t - 1) / 2` as little-endian limbs.
+            #[cfg(feature = "sqrt")]
+            pub(crate) const TRACE_MINUS_ONE_DIV_TWO: [u64; $limbs] =
+                shr(&Self::MODULUS_MINUS_ONE, Self::TWO_ADICITY + 1);
+
+            /// Multiplicative generator as little-endian limbs.
+            #[allow(dead_code)]
+            pub(crate) const GENERATOR_LIMBS: [u64; $limbs] = $generator_limbs;
+
+            /// Primitive `2^s`-th root of unity as little-endian limbs.
+            #[allow(dead_code)]
+            pub(crate) const ROOT_OF_UNITY_LIMBS: [u64; $limbs] = $root_limbs;
+
+            /// Decimal digits of the modulus, see [`to_decimal`].
+            const MODULUS_DECIMAL: ([u8; 128], usize) = to_decimal(&Self::MODULUS);
+        }
+
+        impl ZkField for $t {
+            const MODULUS: &'static [u64] = &<$t>::MODULUS;
+
+            const MODULUS_STR: &'static str = {
+                let (buf, start) = &<$t>::MODULUS_DECIMAL;
+                match std::str::from_utf8(skip_digits(buf, *start)) {
+                    Ok(s) => s,
+                    Err(_) => unreachable!(),
+                }
+            };
+
+            const NUM_BITS: u32 = <$t>::NUM_BITS;
+
+            const CAPACITY: u32 = <$t>::NUM_BITS - 1;
+
+            const TWO_ADICITY: u32 = <$t>::TWO_ADICITY;
+
+            const MULTIPLICATIVE_GENERATOR: Self =
+                field_const!($t, $generator, <$t>::GENERATOR_LIMBS);
+
+            const ROOT_OF_UNITY: Self = field_const!($t, $root, <$t>::ROOT_OF_UNITY_LIMBS);
+        }
+    )*)
+}
+
+zk_field_impl! {
+    Bls12381Base, 6,
+    [
+        0xb9feffffffffaaab, 0x1eabfffeb153ffff, 0x6730d2a0f6b0f624,
+        0x64774b84f38512bf, 0x4b1ba7b6434bacd7, 0x1a0111ea397fe69a,
+    ],
+    2g, [0x2, 0x0, 0x0, 0x0, 0x0, 0x0],
+    4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559786g,
+    Bls12381Base::MODULUS_MINUS_ONE;
+
+    Bls12381Scalar, 4,
+    [0xffffffff00000001, 0x53bda402fffe5bfe, 0x3339d80809a1d805, 0x73eda753299d7d48],
+    7g, [0x7, 0x0, 0x0, 0x0],
+    10238227357739495823651030575849232062558860180284477541189508159991286009131g,
+    [0x3829971f439f0d2b, 0xb63683508c2280b9, 0xd09b681922c813b4, 0x16a2a19edfe81f20];
+
+    Curve25519Base, 4,
+    [0xffffffffffffffed, 0xffffffffffffffff, 0xffffffffffffffff, 0x7fffffffffffffff],
+    2g, [0x2, 0x0, 0x0, 0x0],
+    19681161376707505956807079304988542015446066515923890162744021073123829784752g,
+    [0xc4ee1b274a0ea0b0, 0x2f431806ad2fe478, 0x2b4d00993dfbd7a7, 0x2b8324804fc1df0b];
+
+    Curve25519Scalar, 4,
+    [0x5812631a5cf5d3ed, 0x14def9dea2f79cd6, 0x0000000000000000, 0x1000000000000000],
+    2g, [0x2, 0x0, 0x0, 0x0],
+    4202356475871964119699734399548423449193549369991576068503119564443318355924g,
+    [0xbe8775dfebbe07d4, 0x0ef0565342ce83fe, 0x7d3d6d60abc1c27a, 0x094a7310e07981e7];
+
+    PallasBase, 4,
+    [0x992d30ed00000001, 0x224698fc094cf91b, 0x0000000000000000, 0x4000000000000000],
+    5g, [0x5, 0x0, 0x0, 0x0],
+    19814229590243028906643993866117402072516588566294623396325693409366934201135g,
+    [0xbdad6fabd87ea32f, 0xea322bf2b7bb7584, 0x362120830561f81a, 0x2bce74deac30ebda];
+
+    PallasScalar, 4,
+    [0x8c46eb2100000001, 0x224698fc0994a8dd, 0x0000000000000000, 0x4000000000000000],
+    5g, [0x5, 0x0, 0x0, 0x0],
+    20761624379169977859705911634190121761503565370703356079647768903521299517535g,
+    [0xa70e2c1102b6d05f, 0x9bb97ea3c106f049, 0x9e5c4dfd492ae26e, 0x2de6a9b8746d3f58];
+}
+
+#[cfg(all(test, feature = "software"))]
+mod tests {
+    use super::*;
+
+    /// Returns odd `t` of `p - 1 = 2^s * t` as little-endian limbs.
+    #[cfg(feature = "sqrt")]
+    fn trace<F: ZkField>() -> Vec<u64> {
+        let mut t = F::MODULUS.to_vec();
+        // `p` is odd, so subtracting one only clears the lowest bit.
+        t[0] -= 1;
+        for _ in 0..F::TWO_ADICITY {
+            for i in 0..t.len() {
+                let carry = t.get(i + 1).map_or(0, |limb| limb << 63);
+                t[i] = (t[i] >> 1) | carry;
+            }
+        }
+        t
+    }
+
+    /// Generates tests of roots of unity.
+    #[cfg(feature = "sqrt")]
+    macro_rules! root_of_unity_tests {
+        ($($name:ident, $t:ident;)*) => ($(
+            #[test]
+            fn $name() {
+                let one = $t(builtin_one!($t));
+                let t = trace::<$t>();
+                assert_eq!(t[0] & 1, 1);
+                assert_eq!(<$t>::MULTIPLICATIVE_GENERATOR.pow(&t), <$t>::ROOT_OF_UNITY);
+
+                // The order is `2^k`, where `k` is the number of squarings giving one.
+                let mut x = <$t>::ROOT_OF_UNITY;
+                let mut k = 0;
+                while x != one {
+                    x *= x;
+                    k += 1;
+                }
+                assert_eq!(k, <$t as ZkField>::TWO_ADICITY);
+            }
+        )*)
+    }
+
+    #[cfg(feature = "sqrt")]
+    root_of_unity_tests! {
+        bls12381_base_root_of_unity, Bls12381Base;
+        bls12381_scalar_root_of_unity, Bls12381Scalar;
+        curve25519_base_root_of_unity, Curve25519Base;
+        curve25519_scalar_root_of_unity, Curve25519Scalar;
+        pallas_base_root_of_unity, PallasBase;
+        pallas_scalar_root_of_unity, PallasScalar;
+    }
+
+    /// Checks that decimal modulus is one more than the canonical value of `-1`.
+    fn check_modulus_str<F: ZkField>(one: F) {
+        let mut expected = (-one).to_string().into_bytes();
+        // `p` is an odd prime, so the last digit of `p - 1` is not nine.
+        *expected.last_mut().unwrap() += 1;
+        assert_eq!(F::MODULUS_STR.as_bytes(), expected);
+    }
+
+    #[test]
+    fn modulus_str() {
+        check_modulus_str(Bls12381Base(builtin_one!(Bls12381Base)));
+        check_modulus_str(Bls12381Scalar(builtin_one!(Bls12381Scalar)));
+        check_modulus_str(Curve25519Base(builtin_one!(Curve25519Base)));
+        check_modulus_str(Curve25519Scalar(builtin_one!(Curve25519Scalar)));
+        check_modulus_str(PallasBase(builtin_one!(PallasBase)));
+        check_modulus_str(PallasScalar(builtin_one!(PallasScalar)));
+    }
+}
@@ -1,4 +1,10 @@
 //! Hash implementations.
+//!
+//! Elements are hashed by their canonical representation, so hashes are consistent with `Eq`
+//! and do not depend on the backend.
+//!
+//! Curve wrappers do not implement `Hash`: `rslang` builtins do not expose coordinates
+//! of curve elements, so they have no canonical representation to hash.
 
 use std::hash::{Hash, Hasher};
 
@@ -6,21 +12,21 @@ use super::{
     Bls12381Base, Bls12381Scalar, Curve25519Base, Curve25519Scalar, PallasBase, PallasScalar,
 };
 
-/// Implements [`Hash`], assuming that `Self.0` is `Hash`.
-macro_rules! hash_impl {
+/// Implements [`Hash`] for field wrappers by hashing canonical little-endian limbs.
+macro_rules! field_hash_impl {
     ($($t:ty)*) => ($(
         impl Hash for $t {
-            fn hash<H>(&self, _: &mut H)
+            fn hash<H>(&self, state: &mut H)
             where
                 H: Hasher
             {
-                todo!("hash impls for zk types");
+                self.to_le_limbs().hash(state);
             }
         }
     )*)
 }
 
-hash_impl!(
+field_hash_impl!(
     Bls12381Base
     Bls12381Scalar
     Curve25519Base
@@ -28,3 +34,24 @@ hash_impl!(
     PallasBase
     PallasScalar
 );
+
+#[cfg(all(test, feature = "software"))]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+
+    use super::*;
+
+    fn hash_of<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn field_hash_is_canonical() {
+        let x = PallasBase::from_le_limbs([0x1f, 0, 0, 0]).unwrap();
+        let two = PallasBase::from_le_limbs([2, 0, 0, 0]).unwrap();
+        assert_eq!(hash_of(&x), hash_of(&[0x1fu64, 0, 0, 0]));
+        assert_eq!(hash_of(&(x + x)), hash_of(&(x * two)));
+    }
+}
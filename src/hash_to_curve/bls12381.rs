@@ -0,0 +1,570 @@
+//! Hashing to BLS12-381 G1, suite `BLS12381G1_XMD:SHA-256_SSWU_RO_` of RFC 9380.
+//!
+//! Messages are hashed to two field elements with `expand_message_xmd` over SHA-256,
+//! each of them is mapped to the isogenous curve with simplified SWU map
+//! and then to the curve with an 11-isogeny, and the cofactor of the sum is cleared.
+
+use super::super::hash_to_field::hash_to_field;
+use super::super::{Bls12381, Bls12381Base, Bls12381Scalar};
+
+/// Evaluates polynomial given by coefficients from the lowest degree.
+fn eval(coeffs: &[Bls12381Base], x: Bls12381Base) -> Bls12381Base {
+    coeffs
+        .iter()
+        .rev()
+        .fold(Bls12381Base(builtin_zero!(Bls12381Base)), |acc, &k| acc * x + k)
+}
+
+/// Evaluates monic polynomial given by coefficients from the lowest degree,
+/// except for the leading one.
+fn eval_monic(coeffs: &[Bls12381Base], x: Bls12381Base) -> Bls12381Base {
+    coeffs
+        .iter()
+        .rev()
+        .fold(Bls12381Base(builtin_one!(Bls12381Base)), |acc, &k| acc * x + k)
+}
+
+impl Bls12381 {
+    /// Coefficient `a` of the isogenous curve.
+    const ISO_A: Bls12381Base = field_const!(
+        Bls12381Base,
+        12190336318893619529228877361869031420615612348429846051986726275283378313155663745811710833465465981901188123677g,
+        [
+            0x5cf428082d584c1d, 0x98936f8da0e0f97f, 0xd8e8981aefd881ac,
+            0xb0ea985383ee66a8, 0x3d693a02c96d4982, 0x00144698a3b8e943,
+        ]
+    );
+
+    /// Coefficient `b` of the isogenous curve.
+    const ISO_B: Bls12381Base = field_const!(
+        Bls12381Base,
+        2906670324641927570491258158026293881577086121416628140204402091718288198173574630967936031029026176254968826637280g,
+        [
+            0xd1cc48e98e172be0, 0x5a23215a316ceaa5, 0xa0b9c14fcef35ef5,
+            0x2016c1f0f24f4070, 0x018b12e8753eee3b, 0x12e2908d11688030,
+        ]
+    );
+
+    /// Non-square constant `Z` of simplified SWU map.
+    const SSWU_Z: Bls12381Base = field_const!(Bls12381Base, 11g, [0xb, 0x0, 0x0, 0x0, 0x0, 0x0]);
+
+    /// Effective cofactor `h_eff` of the curve.
+    const H_EFF: Bls12381Scalar =
+        field_const!(Bls12381Scalar, 15132376222941642753g, [0xd201000000010001, 0x0, 0x0, 0x0]);
+
+    /// Coefficients of the isogeny `x` numerator, from the lowest degree.
+    const ISO_X_NUM: [Bls12381Base; 12] = [
+        field_const!(
+            Bls12381Base,
+            2712959285290305970661081772124144179193819192423276218370281158706191519995889425075952244140278856085036081760695g,
+            [
+                0xaeac1662734649b7, 0x5610c2d5f2e62d6e, 0xf2627b56cdb4e2c8,
+                0x6b303e88a2d7005f, 0xb809101dd9981585, 0x11a05f2b1e833340,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            3564859427549639835253027846704205725951033235539816243131874237388832081954622352624080767121604606753339903542203g,
+            [
+                0xe834eef1b3cb83bb, 0x4838f2a6f318c356, 0xf565e33c70d1e86b,
+                0x7c17e75b2f6a8417, 0x0588bab22147a81c, 0x17294ed3e943ab2f,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            2051387046688339481714726479723076305756384619135044672831882917686431912682625619320120082313093891743187631791280g,
+            [
+                0xe0179f9dac9edcb0, 0x958c3e3d2a09729f, 0x6878e501ec68e25c,
+                0xce032473295983e5, 0x1d1048c5d10a9a1b, 0x0d54005db97678ec,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            3612713941521031012780325893181011392520079402153354595775735142359240110423346445050803899623018402874731133626465g,
+            [
+                0xc5b388641d9b6861, 0x5336e25ce3107193, 0xf1b33289f1b33083,
+                0xd7f5e4656a8dbf25, 0x4e0609d307e55412, 0x1778e7166fcc6db7,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            2247053637822768981792833880270996398470828564809439728372634811976089874056583714987807553397615562273407692740057g,
+            [
+                0x51154ce9ac8895d9, 0x985a286f301e77c4, 0x086eeb65982fac18,
+                0x99db995a1257fb3f, 0x6642b4b3e4118e54, 0x0e99726a3199f443,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            3415427104483187489859740871640064348492611444552862448295571438270821994900526625562705192993481400731539293415811g,
+            [
+                0xcd13c1c66f652983, 0xa0870d2dcae73d19, 0x9ed3ab9097e68f90,
+                0xdb3cb17dd952799b, 0x01d1201bf7a74ab5, 0x1630c3250d7313ff,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            2067521456483432583860405634125513059912765526223015704616050604591207046392807563217109432457129564962571408764292g,
+            [
+                0xddd7f225a139ed84, 0x8da25128c1052eca, 0x9008e218f9c86b2a,
+                0xb11586264f0f8ce1, 0x6a3726c38ae652bf, 0x0d6ed6553fe44d29,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            3650721292069012982822225637849018828271936405382082649291891245623305084633066170122780668657208923883092359301262g,
+            [
+                0x9ccb5618e3f0c88e, 0x39b7c8f8c8f475af, 0xa682c62ef0f27533,
+                0x356de5ab275b4db1, 0xe8743884d1117e53, 0x17b81e7701abdbe2,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            1239271775787030039269460763652455868148971086016832054354147730155061349388626624328773377658494412538595239256855g,
+            [
+                0x6d71986a8497e317, 0x4fa295f296b74e95, 0xa2c596c928c5d1de,
+                0xc43b756ce79f5574, 0x7b90b33563be990d, 0x080d3cf1f9a78fc4,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            3479374185711034293956731583912244564891370843071137483962415222733470401948838363051960066766720884717833231600798g,
+            [
+                0x7f241067be390c9e, 0xa3190b2edc032779, 0x676314baf4bb1b7f,
+                0xdd2ecb803a0c5c99, 0x2e0c37515d138f22, 0x169b1f8e1bcfa7c4,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            2492756312273161536685660027440158956721981129429869601638362407515627529461742974364729223659746272460004902959995g,
+            [
+                0xca67df3f1605fb7b, 0xf69b771f8c285dec, 0xd50af36003b14866,
+                0xfa7dccdde6787f96, 0x72d8ec09d2565b0d, 0x10321da079ce07e2,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            1058488477413994682556770863004536636444795456512795473806825292198091015005841418695586811009326456605062948114985g,
+            [
+                0xa9c8ba2e8ba2d229, 0xc24b1b80b64d391f, 0x23c0bf1bc24c6b68,
+                0x31d79d7e22c837bc, 0xbd1e962381edee3d, 0x06e08c248e260e70,
+            ]
+        ),
+    ];
+
+    /// Coefficients of the monic isogeny `x` denominator, from the lowest degree.
+    const ISO_X_DEN: [Bls12381Base; 10] = [
+        field_const!(
+            Bls12381Base,
+            1353092447850172218905095041059784486169131709710991428415161466575141675351394082965234118340787683181925558786844g,
+            [
+                0x993cf9fa40d21b1c, 0xb558d681be343df8, 0x9c9588617fc8ac62,
+                0x01d5ef4ba35b48ba, 0x18b2e62f4bd3fa6f, 0x08ca8d548cff19ae,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            2822220997908397120956501031591772354860004534930174057793539372552395729721474912921980407622851861692773516917759g,
+            [
+                0xe5c8276ec82b3bff, 0x13daa8846cb026e9, 0x0126c2588c48bf57,
+                0x7041e8ca0cf0800c, 0x48b4711298e53636, 0x12561a5deb559c43,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            1717937747208385987946072944131378949849282930538642983149296304709633281382731764122371874602115081850953846504985g,
+            [
+                0xfcc239ba5cb83e19, 0xd6a3d0967c94fedc, 0xfca64e00b11aceac,
+                0x6f89416f5a718cd1, 0x8137e629bff2991f, 0x0b2962fe57a3225e,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            501624051089734157816582944025690868317536915684467868346388760435016044027032505306995281054569109955275640941784g,
+            [
+                0x130de8938dc62cd8, 0x4976d5243eecf5c4, 0x54cca8abc28d6fd0,
+                0x5b08243f16b16551, 0xc83aafef7c40eb54, 0x03425581a58ae2fe,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            3025903087998593826923738290305187197829899948335370692927241015584233559365859980023579293766193297662657497834014g,
+            [
+                0x539d395b3532a21e, 0x9bd29ba81f35781d, 0x8d6b44e833b306da,
+                0xffdfc759a12062bb, 0x0a6f1d5f43e7a07d, 0x13a8e162022914a8,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            2224140216975189437834161136818943039444741035168992629437640302964164227138031844090123490881551522278632040105125g,
+            [
+                0xc02df9a29f6304a5, 0x7400d24bc4228f11, 0x0a43bcef24b8982f,
+                0x395735e9ce9cad4d, 0x55390f7f0506c6e9, 0x0e7355f8e4e667b9,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            1146414465848284837484508420047674663876992808692209238763293935905506532411661921697047880549716175045414621825594g,
+            [
+                0xec2574496ee84a3a, 0xea73b3538f0de06c, 0x4e2e073062aede9c,
+                0x570f5799af53a189, 0x0f3e0c63e0596721, 0x0772caacf1693619,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            3179090966864399634396993677377903383656908036827452986467581478509513058347781039562481806409014718357094150199902g,
+            [
+                0x11f7d99bbdcc5a5e, 0x0fa5b9489d11e2d3, 0x1996e1cdf9822c58,
+                0x6e7f63c21bca68a8, 0x30b3f5b074cf0199, 0x14a7ac2a9d64a8b2,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            1549317016540628014674302140786462938410429359529923207442151939696344988707002602944342203885692366490121021806145g,
+            [
+                0x4776ec3a79a1d641, 0x03826692abba4370, 0x74100da67f398835,
+                0xe07f8d1d7161366b, 0x5e920b3dafc7a3cc, 0x0a10ecf6ada54f82,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            1442797143427491432630626390066422021593505165588630398337491100088557278058060064930663878153124164818522816175370g,
+            [
+                0x2d6384d168ecdd0a, 0x93174e4b4b786500, 0x76df533978f31c15,
+                0xf682b4ee96f7d037, 0x476d6e3eb3a56680, 0x095fc13ab9e92ad4,
+            ]
+        ),
+    ];
+
+    /// Coefficients of the isogeny `y` numerator, from the lowest degree.
+    const ISO_Y_NUM: [Bls12381Base; 16] = [
+        field_const!(
+            Bls12381Base,
+            1393399195776646641963150658816615410692049723305861307490980409834842911816308830479576739332720113414154429643571g,
+            [
+                0xbe9845719707bb33, 0xcd0c7aee9b3ba3c2, 0x2b52af6c956543d3,
+                0x11ad138e48a86952, 0x259d1f094980dcfa, 0x090d97c81ba24ee0,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            2968610969752762946134106091152102846225411740689724909058016729455736597929366401532929068084731548131227395540630g,
+            [
+                0xe097e75a2e41c696, 0xd6c56711962fa8bf, 0x0f906343eb67ad34,
+                0x1223e96c254f383d, 0xd51036d776fb4683, 0x134996a104ee5811,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            122933100683284845219599644396874530871261396084070222155796123161881094323788483360414289333111221370374027338230g,
+            [
+                0xb8dfe240c72de1f6, 0xd26d521628b00523, 0xc344be4b91400da7,
+                0x2552e2d658a31ce2, 0xf4a384c86a3b4994, 0x00cc786baa966e66,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            303251954782077855462083823228569901064301365507057490567314302006681283228886645653148231378803311079384246777035g,
+            [
+                0xa6355c77b0e5f4cb, 0xde405aba9ec61dec, 0x09e4a3ec03251cf9,
+                0xd42aa7b90eeb791c, 0x7898751ad8746757, 0x01f86376e8981c21,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            1353972356724735644398279028378555627591260676383150667237975415318226973994509601413730187583692624416197017403099g,
+            [
+                0x41b6daecf2e8fedb, 0x2ee7f8dc099040a8, 0x79833fd221351adc,
+                0x195536fbe3ce50b8, 0x5caf4fe2a21529c4, 0x08cc03fdefe0ff13,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            3443977503653895028417260979421240655844034880950251104724609885224259484262346958661845148165419691583810082940400g,
+            [
+                0x99b23ab13633a5f0, 0x203f6326c95a8072, 0x76505c3d3ad5544e,
+                0x74a7d0d4afadb7bd, 0x2211e11db8f0a6a0, 0x16603fca40634b6a,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            718493410301850496156792713845282235942975872282052335612908458061560958159410402177452633054233549648465863759602g,
+            [
+                0xc961f8855fe9d6f2, 0x47a87ac2460f415e, 0x5231413c4d634f37,
+                0xe75bb8ca2be184cb, 0xb2c977d027796b3c, 0x04ab0b9bcfac1bbc,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            1466864076415884313141727877156167508644960317046160398342634861648153052436926062434809922037623519108138661903145g,
+            [
+                0xa15e4ca31870fb29, 0x42f64550fedfe935, 0xfd038da6c26c8426,
+                0x170a05bfe3bdd81f, 0xde9926bd2ca6c674, 0x0987c8d5333ab86f,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            1536886493137106337339531461344158973554574987550750910027365237255347020572858445054025958480906372033954157667719g,
+            [
+                0x60370e577bdba587, 0x69d65201c78607a3, 0x1e8b6e6a1f20cabe,
+                0x8f3abd16679dc26c, 0xe88c9e221e4da1bb, 0x09fc4018bd96684b,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            2171468288973248519912068884667133903101171670397991979582205855298465414047741472281361964966463442016062407908400g,
+            [
+                0x2bafaaebca731c30, 0x9b3f7055dd4eba6f, 0x06985e7ed1e4d43b,
+                0xc42a0ca7915af6fe, 0x223abde7ada14a23, 0x0e1bba7a1186bdb5,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            3915937073730221072189646057898966011292434045388986394373682715266664498392389619761133407846638689998746172899634g,
+            [
+                0xe813711ad011c132, 0x31bf3a5cce3fbafc, 0xd1183e416389e610,
+                0xcd2fcbcb6caf493f, 0x0dfd0b8f1d43fb93, 0x19713e47937cd1be,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            3802409194827407598156407709510350851173404795262202653149767739163117554648574333789388883640862266596657730112910g,
+            [
+                0xce07c8a4d0074d8e, 0x49d9cdf41b44d606, 0x2e6bfe7f911f6432,
+                0x523559b8aaf0c246, 0xb918c143fed2edcc, 0x18b46a908f36f6de,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            1707589313757812493102695021134258021969283151093981498394095062397393499601961942449581422761005023512037430861560g,
+            [
+                0x0d4c04f00b971ef8, 0x06c851c1919211f2, 0xc02710e807b4633f,
+                0x7aa7b12a3426b08e, 0xd155096004f53f44, 0x0b182cac101b9399,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            349697005987545415860583335313370109325490073856352967581197273584891698473628451945217286148025358795756956811571g,
+            [
+                0x42d9d3f5db980133, 0xc6cf90ad1c232a64, 0x13e6632d3c40659c,
+                0x757b3b080d4c1580, 0x72fc00ae7be315dc, 0x0245a394ad1eca9b,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            885704436476567581377743161796735879083481447641210566405057346859953524538988296201011389016649354976986251207243g,
+            [
+                0x866b1e715475224b, 0x6ba1049b6579afb7, 0xd9ab0f5d396a7ce4,
+                0x5e673d81d7e86568, 0x02a159f748c4a3fc, 0x05c129645e44cf11,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            3370924952219000111210625390420697640496067348723987858345031683392215988129398381698161406651860675722373763741188g,
+            [
+                0x04b456be69c8b604, 0xb665027efec01c77, 0x57add4fa95af01b2,
+                0xcb181d8f84965a39, 0x4ea50b3b42df2eb5, 0x15e6be4e990f03ce,
+            ]
+        ),
+    ];
+
+    /// Coefficients of the monic isogeny `y` denominator, from the lowest degree.
+    const ISO_Y_DEN: [Bls12381Base; 15] = [
+        field_const!(
+            Bls12381Base,
+            3396434800020507717552209507749485772788165484415495716688989613875369612529138640646200921379825018840894888371137g,
+            [
+                0x01479253b03663c1, 0x07f3688ef60c206d, 0xeec3232b5be72e7a,
+                0x601a6de578980be6, 0x52181140fad0eae9, 0x16112c4c3a9c98b2,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            3907278185868397906991868466757978732688957419873771881240086730384895060595583602347317992689443299391009456758845g,
+            [
+                0x32f6102c2e49a03d, 0x78a4260763529e35, 0xa4a10356f453e01f,
+                0x85c84ff731c4d59c, 0x1a0cbd6c43c348b8, 0x1962d75c2381201e,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            854914566454823955479427412036002165304466268547334760894270240966182605542146252771872707010378658178126128834546g,
+            [
+                0x1e2538b53dbf67f2, 0xa6757cd636f96f89, 0x0c35a5dd279cd2ec,
+                0x78c4855551ae7f31, 0x6faaae7d6e8eb157, 0x058df3306640da27,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            3496628876382137961119423566187258795236027183112131017519536056628828830323846696121917502443333849318934945158166g,
+            [
+                0xa8d26d98445f5416, 0x727364f2c28297ad, 0x123da489e726af41,
+                0xd115c5dbddbcd30e, 0xf20d23bf89edb4d1, 0x16b7d288798e5395,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            1828256966233331991927609917644344011503610008134915752990581590799656305331275863706710232159635159092657073225757g,
+            [
+                0xda39142311a5001d, 0xa20b15dc0fd2eded, 0x542eda0fc9dec916,
+                0xc6d19c9f0f69bbb0, 0xb00cc912f8228ddc, 0x0be0e079545f43e4,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            1362317127649143894542621413133849052553333099883364300946623208643344298804722863920546222860227051989127113848748g,
+            [
+                0x02c6477faaf9b7ac, 0x49f38db9dfa9cce2, 0xc5ecd87b6f0f5a64,
+                0xb70152c65550d881, 0x9fb266eaac783182, 0x08d9e5297186db2d,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            3443845896188810583748698342858554856823966611538932245284665132724280883115455093457486044009395063504744802318172g,
+            [
+                0x3d1a1399126a775c, 0xd5fa9c01a58b1fb9, 0x5dd365bc400a0051,
+                0x5eecfdfa8d0cf8ef, 0xc3ba8734ace9824b, 0x166007c08a99db2f,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            3484671274283470572728732863557945897902920439975203610275006103818288159899345245633896492713412187296754791689945g,
+            [
+                0x60ee415a15812ed9, 0xb920f5b00801dee4, 0xfeb34fd206357132,
+                0xe5a4375efa1f4fd7, 0x03bcddfabba6ff6e, 0x16a3ef08be3ea7ea,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            3755735109429418587065437067067640634211015783636675372165599470771975919172394156249639331555277748466603540045130g,
+            [
+                0x6b233d9d55535d4a, 0x52cfe2f7bb924883, 0xabc5750c4bf39b48,
+                0xf9fb0ce4c6af5920, 0x1a1be54fd1d74cc4, 0x1866c8ed336c6123,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            3459661102222301807083870307127272890283709299202626530836335779816726101522661683404130556379097384249447658110805g,
+            [
+                0x346ef48bb8913f55, 0xc7385ea3d529b35e, 0x5308592e7ea7d4fb,
+                0x3216f763e13d87bb, 0xea820597d94a8490, 0x167a55cda70a6e1c,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            742483168411032072323733249644347333168432665415341249073150659015707795549260947228694495111018381111866512337576g,
+            [
+                0x00f8b49cba8f6aa8, 0x71a5c29f4f830604, 0x0e591b36e636a5c8,
+                0x9c6dd039bb61a629, 0x48f010a01ad2911d, 0x04d2f259eea405bd,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            1662231279858095762833829698537304807741442669992646287950513237989158777254081548205552083108208170765474149568658g,
+            [
+                0x9684b529e2561092, 0x16f968986f7ebbea, 0x8c0f9a88cea79135,
+                0x7f94ff8aefce42d2, 0xf5852c1e48c50c47, 0x0accbb67481d033f,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            1668238650112823419388205992952852912407572045257706138925379268508860023191233729074751042562151098884528280913356g,
+            [
+                0x1e99b138573345cc, 0x93000763e3b90ac1, 0x7d5ceef9a00d9b86,
+                0x543346d98adf0226, 0xc3613144b45f1496, 0x0ad6b9514c767fe3,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            369162719928976119195087327055926326601627748362769544198813069133429557026740823593067700396825489145575282378487g,
+            [
+                0xd1fadc1326ed06f7, 0x420517bd8714cc80, 0xcb748df27942480e,
+                0xbf565b94e72927c1, 0x628bdd0d53cd76f2, 0x02660400eb2e4f3b,
+            ]
+        ),
+        field_const!(
+            Bls12381Base,
+            2164195715141237148945939585099633032390257748382945597506236650132835917087090097395995817229686247227784224263055g,
+            [
+                0x4415473a1d634b8f, 0x5ca2f570f1349780, 0x324efcd6356caa20,
+                0x71c40f65e273b853, 0x6b24255e0d7819c1, 0x0e0fa1d816ddc03e,
+            ]
+        ),
+    ];
+
+    /// Maps affine point of the isogenous curve to the curve.
+    fn iso_map((x, y): (Bls12381Base, Bls12381Base)) -> Self {
+        let x_den = eval_monic(&Self::ISO_X_DEN, x);
+        let y_den = eval_monic(&Self::ISO_Y_DEN, x);
+
+        // Both denominators vanish exactly on the isogeny kernel.
+        if x_den == Bls12381Base(builtin_zero!(Bls12381Base)) {
+            return Self::zero();
+        }
+        let x_num = eval(&Self::ISO_X_NUM, x);
+        let y_num = eval(&Self::ISO_Y_NUM, x);
+        // SAFETY: the isogeny maps points of the isogenous curve onto the curve.
+        unsafe { Self::from_coordinates(x_num / x_den, y * y_num / y_den) }
+    }
+
+    /// Hashes message to curve element with `BLS12381G1_XMD:SHA-256_SSWU_RO_` suite
+    /// of RFC 9380, using domain separation tag `dst`.
+    pub fn hash_to_curve(dst: &[u8], msg: &[u8]) -> Self {
+        let u = hash_to_field::<Bls12381Base>(msg, dst, 2);
+        let (u_0, u_1) = (u[0], u[1]);
+        let q_0 = u_0.map_to_curve_simple_swu(Self::ISO_A, Self::ISO_B, Self::SSWU_Z);
+        let q_1 = u_1.map_to_curve_simple_swu(Self::ISO_A, Self::ISO_B, Self::SSWU_Z);
+        (Self::iso_map(q_0) + Self::iso_map(q_1)) * Self::H_EFF
+    }
+}
+
+#[cfg(all(test, feature = "software"))]
+mod tests {
+    use super::*;
+
+    const DST: &[u8] = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+
+    /// Parses big-endian hexadecimal field element.
+    fn base(hex: &str) -> Bls12381Base {
+        let mut bytes = [0u8; 48];
+        for (i, byte) in bytes.iter_mut().rev().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+        Bls12381Base::from_bytes_le(&bytes).unwrap()
+    }
+
+    fn check(msg: &[u8], x: &str, y: &str) {
+        let expected = unsafe { Bls12381::from_coordinates(base(x), base(y)) };
+        assert_eq!(Bls12381::hash_to_curve(DST, msg), expected);
+    }
+
+    // RFC 9380, Appendix J.9.1.
+    #[test]
+    fn rfc9380_vectors() {
+        check(
+            b"",
+            "052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a1",
+            "08ba738453bfed09cb546dbb0783dbb3a5f1f566ed67bb6be0e8c67e2e81a4cc68ee29813bb7994998f3eae0c9c6a265",
+        );
+        check(
+            b"abc",
+            "03567bc5ef9c690c2ab2ecdf6a96ef1c139cc0b2f284dca0a9a7943388a49a3aee664ba5379a7655d3c68900be2f6903",
+            "0b9c15f3fe6e5cf4211f346271d7b01c8f3b28be689c8429c85b67af215533311f0b8dfaaa154fa6b88176c229f2885d",
+        );
+        check(
+            b"abcdef0123456789",
+            "11e0b079dea29a68f0383ee94fed1b940995272407e3bb916bbf268c263ddd57a6a27200a784cbc248e84f357ce82d98",
+            "03a87ae2caf14e8ee52e51fa2ed8eefe80f02457004ba4d486d6aa1f517c0889501dc7413753f9599b099ebcbbd2d709",
+        );
+        check(
+            format!("q128_{}", "q".repeat(128)).as_bytes(),
+            "15f68eaa693b95ccb85215dc65fa81038d69629f70aeee0d0f677cf22285e7bf58d7cb86eefe8f2e9bc3f8cb84fac488",
+            "1807a1d50c29f430b8cafc4f8638dfeeadf51211e1602a5f184443076715f91bb90a48ba1e370edce6ae1062f5e6dd38",
+        );
+        check(
+            format!("a512_{}", "a".repeat(512)).as_bytes(),
+            "082aabae8b7dedb0e78aeb619ad3bfd9277a2f77ba7fad20ef6aabdc6c31d19ba5a6d12283553294c1825c4b3ca2dcfe",
+            "05b84ae5a942248eea39e1d91030458c40153f3b654ab7872d779ad1e942856a20c438e8d99bc8abfbf74729ce1f7ac8",
+        );
+    }
+}
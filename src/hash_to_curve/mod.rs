@@ -0,0 +1,58 @@
+//! Hashing to curves.
+//!
+//! Field elements are mapped to curves with the simplified SWU map from RFC 9380,
+//! applied to an isogenous curve with nonzero `a * b` and followed by the isogeny.
+
+use super::{Bls12381Base, PallasBase, PallasScalar};
+
+mod bls12381;
+mod pasta;
+
+/// Implements simplified SWU map, RFC 9380 section 6.6.2.
+macro_rules! sswu_impl {
+    ($($t:ident)*) => ($(
+        impl $t {
+            /// Returns `sgn0` of the element, i.e. the parity of its canonical value.
+            pub(crate) fn sgn0(&self) -> bool {
+                self.to_bytes_le()[0] & 1 == 1
+            }
+
+            /// Maps the element to affine point on curve `y^2 = x^3 + a * x + b`,
+            /// where `a * b != 0` and `z` is the non-square constant of the map.
+            pub(crate) fn map_to_curve_simple_swu(&self, a: Self, b: Self, z: Self) -> (Self, Self) {
+                let zero = $t(builtin_zero!($t));
+                let one = $t(builtin_one!($t));
+                let u = *self;
+
+                let u2 = u * u;
+                let tv1 = z * z * u2 * u2 + z * u2;
+                let x1 = if tv1 == zero {
+                    b / (z * a)
+                } else {
+                    -b / a * (one + one / tv1)
+                };
+                let gx1 = x1 * x1 * x1 + a * x1 + b;
+                let (x, y) = match gx1.sqrt() {
+                    Some(y) => (x1, y),
+                    None => {
+                        let x2 = z * u2 * x1;
+                        let gx2 = x2 * x2 * x2 + a * x2 + b;
+                        (x2, gx2.sqrt().expect("gx2 is a square if gx1 is not"))
+                    }
+                };
+
+                if u.sgn0() == y.sgn0() {
+                    (x, y)
+                } else {
+                    (x, -y)
+                }
+            }
+        }
+    )*)
+}
+
+sswu_impl! {
+    Bls12381Base
+    PallasBase
+    PallasScalar
+}
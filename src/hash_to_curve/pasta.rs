@@ -0,0 +1,296 @@
+//! Hashing to Pallas and Vesta curves compatible with `pasta_curves` crate.
+//!
+//! Messages are hashed to two field elements with `expand_message_xmd` over BLAKE2b-512,
+//! each of them is mapped to the isogenous curve `y^2 = x^3 + a' * x + 1265`
+//! with simplified SWU map and then to the curve with a 3-isogeny, and the two points are added.
+//! `pasta_curves` adds the points on the isogenous curve before applying the isogeny instead,
+//! which gives the same result, since the isogeny is a group homomorphism.
+
+use super::super::digest::Blake2b512;
+use super::super::{Pallas, PallasBase, PallasScalar, Vesta};
+
+/// Implements `hash_to_curve`.
+macro_rules! pasta_hash_to_curve_impl {
+    ($($curve:ident, $base:ident, $curve_id:literal,
+       $iso_a:tt, $iso_a_limbs:expr,
+       $iso_b:tt, $iso_b_limbs:expr,
+       $z:tt, $z_limbs:expr,
+       [$($iso:tt, $iso_limbs:expr,)*];)*) => ($(
+        impl $curve {
+            /// Coefficient `a` of the isogenous curve.
+            const ISO_A: $base = field_const!($base, $iso_a, $iso_a_limbs);
+
+            /// Coefficient `b` of the isogenous curve.
+            const ISO_B: $base = field_const!($base, $iso_b, $iso_b_limbs);
+
+            /// Non-square constant `Z` of simplified SWU map.
+            const SSWU_Z: $base = field_const!($base, $z, $z_limbs);
+
+            /// Coefficients of the isogeny rational maps, from the highest degree.
+            const ISOGENY: [$base; 13] = [$(field_const!($base, $iso, $iso_limbs)),*];
+
+            /// Hashes message to two field elements with `expand_message_xmd` from RFC 9380.
+            fn hash_to_field(domain_prefix: &str, message: &[u8]) -> [$base; 2] {
+                const CHUNK_LEN: usize = Blake2b512::OUTPUT_SIZE;
+
+                let dst = [
+                    domain_prefix.as_bytes(),
+                    b"-",
+                    $curve_id.as_bytes(),
+                    b"_XMD:BLAKE2b_SSWU_RO_",
+                ]
+                .concat();
+                assert!(dst.len() < 256, "domain prefix is too long");
+                let dst_len = [dst.len() as u8];
+
+                let mut hasher = Blake2b512::new();
+                hasher.update(&[0; Blake2b512::BLOCK_SIZE]);
+                hasher.update(message);
+                hasher.update(&[0, (2 * CHUNK_LEN) as u8, 0]);
+                hasher.update(&dst);
+                hasher.update(&dst_len);
+                let b_0 = hasher.finalize();
+
+                let mut hasher = Blake2b512::new();
+                hasher.update(&b_0);
+                hasher.update(&[1]);
+                hasher.update(&dst);
+                hasher.update(&dst_len);
+                let b_1 = hasher.finalize();
+
+                let mut hasher = Blake2b512::new();
+                let mut b_0_xor_b_1 = [0u8; CHUNK_LEN];
+                for (x, (l, r)) in b_0_xor_b_1.iter_mut().zip(b_0.iter().zip(&b_1)) {
+                    *x = l ^ r;
+                }
+                hasher.update(&b_0_xor_b_1);
+                hasher.update(&[2]);
+                hasher.update(&dst);
+                hasher.update(&dst_len);
+                let b_2 = hasher.finalize();
+
+                // Outputs are big-endian integers.
+                [b_1, b_2].map(|mut bytes| {
+                    bytes.reverse();
+                    <$base>::from_bytes_le_mod_order(&bytes)
+                })
+            }
+
+            /// Maps affine point of the isogenous curve to the curve.
+            fn iso_map((x, y): ($base, $base)) -> Self {
+                let k = &Self::ISOGENY;
+                let num_x = ((k[0] * x + k[1]) * x + k[2]) * x + k[3];
+                let div_x = (x + k[4]) * x + k[5];
+                let num_y = (((k[6] * x + k[7]) * x + k[8]) * x + k[9]) * y;
+                let div_y = ((x + k[10]) * x + k[11]) * x + k[12];
+
+                // Both denominators vanish exactly on the isogeny kernel.
+                if div_x == $base(builtin_zero!($base)) {
+                    return Self::zero();
+                }
+                // SAFETY: the isogeny maps points of the isogenous curve onto the curve.
+                unsafe { Self::from_coordinates(num_x / div_x, num_y / div_y) }
+            }
+
+            /// Hashes message to curve element the same way as `hash_to_curve`
+            /// of `pasta_curves` does, with domain separation by `domain_prefix`.
+            ///
+            /// Panics if domain separation tag, which is `domain_prefix` followed by
+            /// `-<curve>_XMD:BLAKE2b_SSWU_RO_`, is 256 bytes or longer, i.e. if `domain_prefix`
+            /// is longer than 227 bytes for Pallas or 228 bytes for Vesta.
+            pub fn hash_to_curve(domain_prefix: &str, message: &[u8]) -> Self {
+                let [u_0, u_1] = Self::hash_to_field(domain_prefix, message);
+                let q_0 = u_0.map_to_curve_simple_swu(Self::ISO_A, Self::ISO_B, Self::SSWU_Z);
+                let q_1 = u_1.map_to_curve_simple_swu(Self::ISO_A, Self::ISO_B, Self::SSWU_Z);
+                Self::iso_map(q_0) + Self::iso_map(q_1)
+            }
+        }
+    )*)
+}
+
+pasta_hash_to_curve_impl! {
+    Pallas, PallasBase, "pallas",
+    10949663248450308183708987909873589833737836120165333298109615750520499732811g,
+    [0x92bb4b0b657a014b, 0xb74134581a27a59f, 0x49be2d7258370742, 0x18354a2eb0ea8c9c],
+    1265g, [0x4f1, 0x0, 0x0, 0x0],
+    28948022309329048855892746252171976963363056481941560715954676764349967630324g,
+    [0x992d30ecfffffff4, 0x224698fc094cf91b, 0x0000000000000000, 0x4000000000000000],
+    [
+        6432893846517566412420610278260439325191790329320346825767705947633326140075g,
+        [0x775f6034aaaaaaab, 0x4081775473d8375b, 0xe38e38e38e38e38e, 0x0e38e38e38e38e38],
+        23989696149150192365340222745168215001509815558210986772351135915822265203574g,
+        [0x8cf863b02814fb76, 0x0f93b82ee4b99495, 0x267c7ffa51cf412a, 0x3509afd51872d88e],
+        10492611921771203378452795982353351666191589197598957448093274638589204800759g,
+        [0x0eb64faef37ea4f7, 0x380af066cfeb6d69, 0x98c7d7ac3d98fd13, 0x17329b9ec5253753],
+        12865787693035132824841220556520878650383580658640693651535411895266652280192g,
+        [0xeebec06955555580, 0x8102eea8e7b06eb6, 0xc71c71c71c71c71c, 0x1c71c71c71c71c71],
+        13271109177048389296812780941310096270046944650307955939477485891950613419807g,
+        [0xc47f2ab668bcd71f, 0x9c434ac1c96b6980, 0x5a607fcce0494a79, 0x1d572e7ddc099cff],
+        22768321103861051515190775253992702316905399997697804654926324362758820947460g,
+        [0x2aa3af1eae5b6604, 0xb4abf9fb9a1fc81c, 0x1d13bf2a7f22b105, 0x325669becaecd5d1],
+        11793638718615538422771118843477472096184948937087302513907460903994431256804g,
+        [0x5ad985b5e38e38e4, 0x7642b01ad461bad2, 0x4bda12f684bda12f, 0x1a12f684bda12f68],
+        11994848074575096182670111372584107500754907779105493386175567957911132601787g,
+        [0xc67c31d8140a7dbb, 0x07c9dc17725cca4a, 0x133e3ffd28e7a095, 0x1a84d7ea8c396c47],
+        28823569610051396102362669851238297121581474897215657071023781420043761726004g,
+        [0x02e2be87d225b234, 0x1765e924f7459378, 0x303216cce1db9ff1, 0x3fb98ff0d2ddcadd],
+        1072148974419594402070101713043406554198631721553391137627950991272221023311g,
+        [0x93e53ab371c71c4f, 0x0ac03e8e134eb3e4, 0x7b425ed097b425ed, 0x025ed097b425ed09],
+        5432652610908059517272798285879155923388888734491153551238890455750936314542g,
+        [0x5a28279b1d1b42ae, 0x5941a3a4a97aa1b3, 0x0790bfb3506defb6, 0x0c02c5bcca0e6b7f],
+        10408918692925056833786833257634153023990087029210292532869619559576527581706g,
+        [0x4d90ab820b12320a, 0xd976bbfabbc5661d, 0x573b3d7f7d681310, 0x17033d3c60c68173],
+        28948022309329048855892746252171976963363056481941560715954676764349967629797g,
+        [0x992d30ecfffffde5, 0x224698fc094cf91b, 0x0000000000000000, 0x4000000000000000],
+    ];
+
+    Vesta, PallasScalar, "vesta",
+    17413348858408915339762682399132325137863850198379221683097628341577494210225g,
+    [0xc515ad7242eaa6b1, 0x9673928c7d01b212, 0x81639c4d96f78773, 0x267f9b2ee592271a],
+    1265g, [0x4f1, 0x0, 0x0, 0x0],
+    28948022309329048855892746252171976963363056481941647379679742748393362948084g,
+    [0x8c46eb20fffffff4, 0x224698fc0994a8dd, 0x0000000000000000, 0x4000000000000000],
+    [
+        25731575386070265649682441113041757300767161317281464337493104665238544842753g,
+        [0x43cd42c800000001, 0x0205dd51cfa0961a, 0x8e38e38e38e38e39, 0x38e38e38e38e38e3],
+        13377367003779316331268047403600734872799183885837485433911493934102207511749g,
+        [0x8b95c6aaf703bcc5, 0x216b8861ec72bd5d, 0xacecf10f5f7c09a2, 0x1d935247b4473d17],
+        11064082577423419940183149293632076317553812518550871517841037420579891210813g,
+        [0xaeac67bbeb586a3d, 0xd59d03d23b39cb11, 0xed7ee4a9cdf78f8f, 0x18760c7f7a9ad20d],
+        22515128462811482443472135973911537638171266152621281295306466582083726737451g,
+        [0xfb539a6f0000002b, 0xe1c521a795ac8356, 0x1c71c71c71c71c71, 0x31c71c71c71c71c7],
+        4604213796697651557841441623718706001740429044770779386484474413346415813353g,
+        [0xb7284f7eaf21a2e9, 0xa3ad678129b604d3, 0x1454798a5b5c56b2, 0x0a2de485568125d5],
+        9250006497141849826017568406346290940322373181457057184910582871723433210981g,
+        [0xf169c187d2533465, 0x30cd6d53df49d235, 0x0c621de8b91c242a, 0x14735171ee542778],
+        8577191795356755216560813704347252433589053772427154779164368221746181614251g,
+        [0x6bef1642aaaaaaab, 0x5601f4709a8adcb3, 0xda12f684bda12f68, 0x12f684bda12f684b],
+        21162694656554182593580396827886355918081120183889566406795618341247785229923g,
+        [0x8bee58e5fb81de63, 0x21d910aefb03b31d, 0xd6767887afbe04d1, 0x2ec9a923da239e8b],
+        11620280474556824258112134491145636201000922752744881519070727793732904824884g,
+        [0x4986913ab4443034, 0x97a3ca5c24e9ea63, 0x66d1466e9de10e64, 0x19b0d87e16e25788],
+        13937936667454727226911322269564285204582212380194126516142098360337545123123g,
+        [0x8f64842c55555533, 0x8bc32d36fb21a6a3, 0x425ed097b425ed09, 0x1ed097b425ed097b],
+        21380331849711001764708535561664047484292171808126992769566582994216305194078g,
+        [0x58dfecce86b2745e, 0x06a767bfc35b5bac, 0x9e7eb64f890a820c, 0x2f44d6c801c1b8bf],
+        27750019491425549478052705219038872820967119544371171554731748615170299632943g,
+        [0xd43d449776f99d2f, 0x926847fb9ddd76a1, 0x252659ba2b546c7e, 0x3d59f455cafc7668],
+        28948022309329048855892746252171976963363056481941647379679742748393362947557g,
+        [0x8c46eb20fffffde5, 0x224698fc0994a8dd, 0x0000000000000000, 0x4000000000000000],
+    ];
+}
+
+#[cfg(all(test, feature = "software"))]
+mod tests {
+    use super::*;
+
+    // Source: `pasta_curves` 0.5.1, affine coordinates of
+    // `pallas::Point::hash_to_curve("z.cash:test")(message).to_affine()` and the same for
+    // `vesta::Point`, printed as little-endian limbs of `to_repr()`.
+    #[test]
+    fn pallas_matches_pasta_curves() {
+        let hash = |message: &[u8]| Pallas::hash_to_curve("z.cash:test", message);
+        let affine = |x, y| {
+            let x = PallasBase::from_le_limbs(x).unwrap();
+            let y = PallasBase::from_le_limbs(y).unwrap();
+            unsafe { Pallas::from_coordinates(x, y) }
+        };
+        assert_eq!(
+            hash(b"Trans rights now!"),
+            affine(
+                [0x36695c9b640b6bd3, 0x2340257d0f187a02, 0xff23df3d88c26f95, 0x1818cda31ffdc8c3],
+                [0x95f2f1efc9b1280c, 0x289f6f9a27741603, 0x2166a0306332e126, 0x01e2088851012375],
+            ),
+        );
+        assert_eq!(
+            hash(b""),
+            affine(
+                [0x2dd215db24ea162c, 0x673c05c3baf6c2cb, 0xc9f42612e73aeed8, 0x015897b5287bed6c],
+                [0x2775f3b895403589, 0xa18ce958259ff0ff, 0xf3fd836c380b3bcd, 0x0ff52bee88954f82],
+            ),
+        );
+        assert_eq!(
+            hash(b"hello"),
+            affine(
+                [0xdf97e004d7abbf93, 0x541ff73e7b95d498, 0x092db9249d1d96b2, 0x3d7fc29fa52fb474],
+                [0x644698ac3a23e6bb, 0x12ab27d52eda49a6, 0x61de940b284f55f3, 0x0e0982e82dc9c462],
+            ),
+        );
+    }
+
+    #[test]
+    fn vesta_matches_pasta_curves() {
+        let hash = |message: &[u8]| Vesta::hash_to_curve("z.cash:test", message);
+        let affine = |x, y| {
+            let x = PallasScalar::from_le_limbs(x).unwrap();
+            let y = PallasScalar::from_le_limbs(y).unwrap();
+            unsafe { Vesta::from_coordinates(x, y) }
+        };
+        assert_eq!(
+            hash(b"Trans rights now!"),
+            affine(
+                [0x8d4502827a31d587, 0xb19b76ebdf4df7c8, 0xcde553caa78f0cdd, 0x29f96e1924d14724],
+                [0xe5eda77bbda84342, 0x4d624392c1c5faeb, 0xf4551b8ffed3c007, 0x33bffb1adebd8759],
+            ),
+        );
+        assert_eq!(
+            hash(b""),
+            affine(
+                [0x9cbe15a7a05749c2, 0x160543c2f5d7b052, 0xc7dfaeafdedf0dae, 0x0125c4dd7127efeb],
+                [0xd6d6597904df4817, 0xdce2b45465f06068, 0xe14c9482465c963c, 0x296c814d449475ef],
+            ),
+        );
+        assert_eq!(
+            hash(b"hello"),
+            affine(
+                [0xf3f2637084849204, 0xd0a87f8c3c3dae80, 0xc95f91b3411bd6cb, 0x2e983e009cf3b86b],
+                [0x2341db7f50b2e106, 0x91c864a5208b2a81, 0x1fb9374bdbc0fb13, 0x310fb8f3316d069a],
+            ),
+        );
+    }
+
+    /// Converts Jacobian coordinates `(X, Y, Z)` to affine `(X / Z^2, Y / Z^3)`.
+    macro_rules! from_jacobian {
+        ($curve:ty, $base:ident, $x:expr, $y:expr, $z:expr) => {{
+            let x = $base::from_le_limbs($x).unwrap();
+            let y = $base::from_le_limbs($y).unwrap();
+            let z = $base::from_le_limbs($z).unwrap();
+            let z2 = z * z;
+            unsafe { <$curve>::from_coordinates(x / z2, y / (z2 * z)) }
+        }};
+    }
+
+    // Source: `test_hash_to_curve` in `src/pallas.rs` of `pasta_curves` 0.5.1,
+    // Jacobian coordinates
+    // x = 0x36a6e3a9c50b7b6540cb002c977c82f37f8a875fb51eb35327ee1452e6ce7947,
+    // y = 0x01da3b4403d73252f2d7e9c19bc23dc6a080f2d02f8262fca4f7e3d756ac6a7c,
+    // z = 0x1d48103df8fcbb70d1809c1806c95651dd884a559fec0549658537ce9d94bed9.
+    #[test]
+    fn pallas_upstream_vector() {
+        let expected = from_jacobian!(
+            Pallas,
+            PallasBase,
+            [0x27ee1452e6ce7947, 0x7f8a875fb51eb353, 0x40cb002c977c82f3, 0x36a6e3a9c50b7b65],
+            [0xa4f7e3d756ac6a7c, 0xa080f2d02f8262fc, 0xf2d7e9c19bc23dc6, 0x01da3b4403d73252],
+            [0x658537ce9d94bed9, 0xdd884a559fec0549, 0xd1809c1806c95651, 0x1d48103df8fcbb70]
+        );
+        assert_eq!(Pallas::hash_to_curve("z.cash:test", b"Trans rights now!"), expected);
+    }
+
+    // Source: `test_hash_to_curve` in `src/vesta.rs` of `pasta_curves` 0.5.1,
+    // Jacobian coordinates
+    // x = 0x12763505036e0e1a6684b7a7d8d5afb7378cc2b191a95e34f44824a06fcbd08e,
+    // y = 0x0256eafc0188b79bfa7c4b2b393893ddc298e90da500fa4a9aee17c2ea4240e6,
+    // z = 0x1b58d4aa4d68c3f4d9916b77c79ff9911597a27f2ee46244e98eb9615172d2ad.
+    #[test]
+    fn vesta_upstream_vector() {
+        let expected = from_jacobian!(
+            Vesta,
+            PallasScalar,
+            [0xf44824a06fcbd08e, 0x378cc2b191a95e34, 0x6684b7a7d8d5afb7, 0x12763505036e0e1a],
+            [0x9aee17c2ea4240e6, 0xc298e90da500fa4a, 0xfa7c4b2b393893dd, 0x0256eafc0188b79b],
+            [0xe98eb9615172d2ad, 0x1597a27f2ee46244, 0xd9916b77c79ff991, 0x1b58d4aa4d68c3f4]
+        );
+        assert_eq!(Vesta::hash_to_curve("z.cash:test", b"hello"), expected);
+    }
+}
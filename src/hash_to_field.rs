@@ -0,0 +1,73 @@
+//! Hashing to fields, RFC 9380 section 5.
+
+use super::expand_message::{expand_message_xmd, expand_message_xof};
+use super::{
+    Bls12381Base, Bls12381Scalar, Curve25519Base, Curve25519Scalar, PallasBase, PallasScalar,
+};
+
+/// Field which elements can be obtained from uniformly random bytes without noticeable bias.
+pub trait HashToField: Sized {
+    /// Number of uniformly random bytes consumed per element,
+    /// `ceil((ceil(log2(p)) + k) / 8)` for security level `k = 128`.
+    const L: usize;
+
+    /// Creates field element from `L` bytes encoding big-endian integer,
+    /// reducing it modulo field modulus.
+    ///
+    /// Panics if length of `bytes` is not `L`.
+    fn from_uniform_bytes(bytes: &[u8]) -> Self;
+}
+
+/// Implements [`HashToField`].
+macro_rules! hash_to_field_impl {
+    ($($t:ty)*) => ($(
+        impl HashToField for $t {
+            // `usize::div_ceil` needs Rust 1.73.
+            #[allow(clippy::manual_div_ceil)]
+            const L: usize = (<$t>::NUM_BITS as usize + 128 + 7) / 8;
+
+            fn from_uniform_bytes(bytes: &[u8]) -> Self {
+                let mut le_bytes = [0u8; <$t as HashToField>::L];
+                le_bytes.copy_from_slice(bytes);
+                le_bytes.reverse();
+                Self::from_bytes_le_mod_order(&le_bytes)
+            }
+        }
+    )*)
+}
+
+hash_to_field_impl! {
+    Bls12381Base
+    Bls12381Scalar
+    Curve25519Base
+    Curve25519Scalar
+    PallasBase
+    PallasScalar
+}
+
+/// Hashes message to `count` field elements with `expand_message_xmd` over SHA-256,
+/// using domain separation tag `dst`.
+///
+/// # Panics
+///
+/// Panics if `count * F::L` is greater than `255 * 32`, the longest output
+/// of `expand_message_xmd` over SHA-256.
+pub fn hash_to_field<F: HashToField>(msg: &[u8], dst: &[u8], count: usize) -> Vec<F> {
+    expand_message_xmd(msg, dst, count * F::L)
+        .chunks(F::L)
+        .map(F::from_uniform_bytes)
+        .collect()
+}
+
+/// Hashes message to `count` field elements with `expand_message_xof` over SHAKE128,
+/// using domain separation tag `dst`.
+///
+/// # Panics
+///
+/// Panics if `count * F::L` is greater than `65535`, the longest output of `expand_message_xof`.
+pub fn hash_to_field_xof<F: HashToField>(msg: &[u8], dst: &[u8], count: usize) -> Vec<F> {
+    expand_message_xof(msg, dst, count * F::L)
+        .chunks(F::L)
+        .map(F::from_uniform_bytes)
+        .collect()
+}
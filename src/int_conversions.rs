@@ -1,41 +1,136 @@
 //! Implementations of conversions from different integers.
+//!
+//! Conversions go through canonical little-endian limbs, so they don't rely on builtin casts,
+//! which `rslang` does not implement yet. With `software` backend limbs are taken directly,
+//! otherwise it costs one doubling per significant bit of the value.
+//! All field moduli are wider than 128 bits, thus no reduction is ever needed.
 
 use super::{
     Bls12381Base, Bls12381Scalar, Curve25519Base, Curve25519Scalar, PallasBase, PallasScalar,
 };
 
-/// Implements `From<T>`, assuming that `Self.0` is convertable using `as`.
-macro_rules! from_impls {
-    ($t:ty, $builtin:ty, $($integer:ident)*) => ($(
+/// Implements `From<T>` for unsigned integer `T` via canonical little-endian limbs.
+macro_rules! from_unsigned_impls {
+    ($t:ty, $limbs:literal, $($integer:ident)*) => ($(
+        impl From<$integer> for $t {
+            fn from(value: $integer) -> Self {
+                let value = u128::from(value);
+                let mut limbs = [0u64; $limbs];
+                limbs[0] = value as u64;
+                limbs[1] = (value >> 64) as u64;
+                Self::from_le_limbs(limbs).expect("moduli are wider than 128 bits")
+            }
+        }
+    )*)
+}
+
+/// Implements `From<T>` for signed integer `T`, mapping negative `x` to `p - |x|`.
+macro_rules! from_signed_impls {
+    ($t:ty, $($integer:ident)*) => ($(
         impl From<$integer> for $t {
-            fn from(_value: $integer) -> Self {
-                // Self(value as $builtin)
-                todo!("field types casts are not yet implemented");
+            fn from(value: $integer) -> Self {
+                let abs = Self::from(value.unsigned_abs());
+                if value < 0 {
+                    -abs
+                } else {
+                    abs
+                }
+            }
+        }
+    )*)
+}
+
+/// Implements `From<T>` for `bool` and all primitive integers.
+macro_rules! from_impls {
+    ($($t:ty, $limbs:literal;)*) => ($(
+        impl From<bool> for $t {
+            fn from(value: bool) -> Self {
+                if value {
+                    Self(builtin_one!($t))
+                } else {
+                    Self(builtin_zero!($t))
+                }
             }
         }
+
+        from_unsigned_impls!($t, $limbs, u8 u16 u32 u64 u128);
+        from_signed_impls!($t, i8 i16 i32 i64 i128);
     )*)
 }
 
-from_impls!(
-    Bls12381Base, __zkllvm_field_bls12381_base, bool u8 u16 u32 u64 u128 i8 i16 i32 i64 i128
-);
+from_impls! {
+    Bls12381Base, 6;
+    Bls12381Scalar, 4;
+    Curve25519Base, 4;
+    Curve25519Scalar, 4;
+    PallasBase, 4;
+    PallasScalar, 4;
+}
+
+#[cfg(all(test, feature = "software"))]
+mod tests {
+    use super::*;
+    use crate::ZkField;
+
+    /// Generates tests of conversions from integers.
+    macro_rules! int_conversions_tests {
+        ($($name:ident, $t:ident;)*) => ($(
+            #[test]
+            fn $name() {
+                let zero = $t(builtin_zero!($t));
+                let one = $t(builtin_one!($t));
+                let two_64 = <$t>::from(u64::MAX) + one;
 
-from_impls!(
-    Bls12381Scalar, __zkllvm_field_bls12381_base, bool u8 u16 u32 u64 u128 i8 i16 i32 i64 i128
-);
+                assert_eq!(<$t>::from(false), zero);
+                assert_eq!(<$t>::from(true), one);
 
-from_impls!(
-    Curve25519Base, __zkllvm_field_bls12381_base, bool u8 u16 u32 u64 u128 i8 i16 i32 i64 i128
-);
+                // Every integer is below the modulus, so limbs are the value itself.
+                let mut max = [0u64; <$t as ZkField>::MODULUS.len()];
+                max[0] = u64::MAX;
+                assert_eq!(<$t>::from(u8::MAX).to_le_limbs()[0], u64::from(u8::MAX));
+                assert_eq!(<$t>::from(u16::MAX).to_le_limbs()[0], u64::from(u16::MAX));
+                assert_eq!(<$t>::from(u32::MAX).to_le_limbs()[0], u64::from(u32::MAX));
+                assert_eq!(<$t>::from(u64::MAX).to_le_limbs(), max);
+                max[1] = u64::MAX;
+                assert_eq!(<$t>::from(u128::MAX).to_le_limbs(), max);
+                let u64_max = <$t>::from(u64::MAX);
+                assert_eq!(<$t>::from(u128::MAX), u64_max * two_64 + u64_max);
 
-from_impls!(
-    Curve25519Scalar, __zkllvm_field_bls12381_base, bool u8 u16 u32 u64 u128 i8 i16 i32 i64 i128
-);
+                let x = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210_u128;
+                let (hi, lo) = ((x >> 64) as u64, x as u64);
+                assert_eq!(<$t>::from(x), <$t>::from(hi) * two_64 + <$t>::from(lo));
 
-from_impls!(
-    PallasBase, __zkllvm_field_bls12381_base, bool u8 u16 u32 u64 u128 i8 i16 i32 i64 i128
-);
+                for value in [0u8, 1, 2, 0x80, u8::MAX] {
+                    let expected = <$t>::from(value);
+                    assert_eq!(<$t>::from(u16::from(value)), expected);
+                    assert_eq!(<$t>::from(u32::from(value)), expected);
+                    assert_eq!(<$t>::from(u64::from(value)), expected);
+                    assert_eq!(<$t>::from(u128::from(value)), expected);
+                }
 
-from_impls!(
-    PallasScalar, __zkllvm_field_bls12381_base, bool u8 u16 u32 u64 u128 i8 i16 i32 i64 i128
-);
+                // Negative integers are mapped to `p - |x|`.
+                assert_eq!(<$t>::from(0i8), zero);
+                assert_eq!(<$t>::from(-1i8), -one);
+                assert_eq!(<$t>::from(i8::MIN), -<$t>::from(128u8));
+                assert_eq!(<$t>::from(i8::MAX), <$t>::from(127u8));
+                assert_eq!(<$t>::from(i16::MIN), -<$t>::from(i16::MIN.unsigned_abs()));
+                assert_eq!(<$t>::from(i32::MIN), -<$t>::from(i32::MIN.unsigned_abs()));
+                assert_eq!(<$t>::from(i64::MIN), -<$t>::from(i64::MIN.unsigned_abs()));
+                assert_eq!(<$t>::from(i64::MAX), <$t>::from(i64::MAX as u64));
+                assert_eq!(<$t>::from(i128::MIN), -<$t>::from(1u128 << 127));
+                assert_eq!(<$t>::from(i128::MAX), <$t>::from(u128::MAX >> 1));
+                assert_eq!(<$t>::from(i128::MIN) + <$t>::from(i128::MAX), -one);
+                assert_eq!(<$t>::from(-(x as i128)), -<$t>::from(x));
+            }
+        )*)
+    }
+
+    int_conversions_tests! {
+        bls12381_base_int_conversions, Bls12381Base;
+        bls12381_scalar_int_conversions, Bls12381Scalar;
+        curve25519_base_int_conversions, Curve25519Base;
+        curve25519_scalar_int_conversions, Curve25519Scalar;
+        pallas_base_int_conversions, PallasBase;
+        pallas_scalar_int_conversions, PallasScalar;
+    }
+}
@@ -16,7 +16,7 @@ macro_rules! sum_product_impl {
         impl Sum for $t {
             fn sum<I: Iterator<Item=Self>>(iter: I) -> Self {
                 iter.fold(
-                    Self(0g),
+                    Self(builtin_zero!($t)),
                     |a, b| a + b,
                 )
             }
@@ -25,7 +25,7 @@ macro_rules! sum_product_impl {
         impl Product for $t {
             fn product<I: Iterator<Item=Self>>(iter: I) -> Self {
                 iter.fold(
-                    Self(1g),
+                    Self(builtin_one!($t)),
                     |a, b| a * b,
                 )
             }
@@ -34,7 +34,7 @@ macro_rules! sum_product_impl {
         impl<'a> Sum<&'a $t> for $t {
             fn sum<I: Iterator<Item=&'a Self>>(iter: I) -> Self {
                 iter.fold(
-                    Self(0g),
+                    Self(builtin_zero!($t)),
                     |a, b| a + b,
                 )
             }
@@ -43,7 +43,7 @@ macro_rules! sum_product_impl {
         impl<'a> Product<&'a $t> for $t {
             fn product<I: Iterator<Item=&'a Self>>(iter: I) -> Self {
                 iter.fold(
-                    Self(1g),
+                    Self(builtin_one!($t)),
                     |a, b| a * b,
                 )
             }
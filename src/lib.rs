@@ -6,6 +6,83 @@ use std::ops::{
     SubAssign,
 };
 
+#[cfg(feature = "software")]
+pub mod software;
+#[cfg(feature = "software")]
+use software::{
+    __zkllvm_curve_bls12381, __zkllvm_curve_curve25519, __zkllvm_curve_pallas,
+    __zkllvm_curve_vesta, __zkllvm_field_bls12381_base, __zkllvm_field_bls12381_scalar,
+    __zkllvm_field_curve25519_base, __zkllvm_field_curve25519_scalar, __zkllvm_field_pallas_base,
+    __zkllvm_field_pallas_scalar,
+};
+
+#[cfg(all(feature = "software", target_arch = "assigner"))]
+compile_error!("`software` backend cannot be used for circuits, disable `software` feature");
+
+/// Expands to constant of field wrapper `T`, given both as `rslang` field literal
+/// and as little-endian limbs for `software` backend.
+#[cfg(not(feature = "software"))]
+#[allow(unused_macros)]
+macro_rules! field_const {
+    ($t:ident, $literal:tt, $limbs:expr) => { $t($literal) };
+}
+
+/// Expands to constant of field wrapper `T`, given both as `rslang` field literal
+/// and as little-endian limbs for `software` backend.
+#[cfg(feature = "software")]
+#[allow(unused_macros)]
+macro_rules! field_const {
+    ($t:ident, $literal:tt, $limbs:expr) => {
+        $t(<<$t as ::std::ops::Deref>::Target>::from_le_limbs_unwrap($limbs))
+    };
+}
+
+/// Expands to builtin zero of field wrapper `T`.
+#[cfg(not(feature = "software"))]
+#[allow(unused_macros)]
+macro_rules! builtin_zero {
+    ($t:ty) => { 0g };
+}
+
+/// Expands to builtin zero of field wrapper `T`.
+#[cfg(feature = "software")]
+#[allow(unused_macros)]
+macro_rules! builtin_zero {
+    ($t:ty) => { <<$t as ::std::ops::Deref>::Target>::ZERO };
+}
+
+/// Expands to builtin one of field wrapper `T`.
+#[cfg(not(feature = "software"))]
+#[allow(unused_macros)]
+macro_rules! builtin_one {
+    ($t:ty) => { 1g };
+}
+
+/// Expands to builtin one of field wrapper `T`.
+#[cfg(feature = "software")]
+#[allow(unused_macros)]
+macro_rules! builtin_one {
+    ($t:ty) => { <<$t as ::std::ops::Deref>::Target>::ONE };
+}
+
+/// Expands to constant of field wrapper `T`, used by `field!` macro.
+#[cfg(not(feature = "software"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __field_literal {
+    ($t:ident, $literal:tt, $limbs:expr) => { $crate::$t($literal) };
+}
+
+/// Expands to constant of field wrapper `T`, used by `field!` macro.
+#[cfg(feature = "software")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __field_literal {
+    ($t:ident, $literal:tt, $limbs:expr) => {
+        $crate::$t(<<$crate::$t as ::std::ops::Deref>::Target>::from_le_limbs($limbs).unwrap())
+    };
+}
+
 /// Bls12381 curve type.
 ///
 /// Wrapper for `__zkllvm_curve_bls12381` type.
@@ -189,10 +266,18 @@ curve_arith_impl! {
     Vesta, VestaScalar
 }
 
+/// Implements constructors of curve wrappers.
+///
+/// `rslang` builtins do not expose coordinates of curve elements, so wrappers have no
+/// coordinate accessors. Host-side encoders read them from `software` backend types.
 macro_rules! curve_init_impl {
     ($($curve:ty, $builtin:ty, $base:ty)*) => ($(
         impl $curve {
             /// Create curve element from base field coordinates.
+            ///
+            /// # Safety
+            ///
+            /// Coordinates are not checked to be a point on the curve.
             #[inline(always)]
             pub unsafe fn from_coordinates(x: $base, y: $base) -> Self {
                 Self(<$builtin>::from_coordinates(x.0, y.0))
@@ -209,6 +294,12 @@ macro_rules! curve_init_impl {
             pub fn one() -> Self {
                 Self(<$builtin>::one())
             }
+
+            /// Returns `true` if the curve element is neutral.
+            #[inline(always)]
+            pub fn is_zero(&self) -> bool {
+                *self == Self::zero()
+            }
         }
     )*)
 }
@@ -220,6 +311,74 @@ curve_init_impl! {
     Vesta, __zkllvm_curve_vesta, VestaBase
 }
 
+#[cfg(any(
+    feature = "hash",
+    feature = "int-conversions",
+    feature = "repr",
+    feature = "radix",
+    test
+))]
+mod limbs;
+
+#[cfg(feature = "repr")]
+mod repr;
+
+#[cfg(feature = "sqrt")]
+mod sqrt;
+#[cfg(feature = "sqrt")]
+pub use sqrt::LegendreSymbol;
+
+#[cfg(feature = "radix")]
+mod radix;
+#[cfg(feature = "radix")]
+pub use radix::ParseFieldError;
+
+mod field;
+pub use field::ZkField;
+
+mod curve;
+pub use curve::ZkCurve;
+
+#[cfg(feature = "validation")]
+mod validation;
+#[cfg(feature = "validation")]
+pub use validation::CurveError;
+
+#[cfg(all(feature = "compression", feature = "software"))]
+mod compression;
+
+#[cfg(feature = "hash-to-field")]
+mod digest;
+
+#[cfg(feature = "hash-to-field")]
+mod expand_message;
+
+#[cfg(feature = "hash-to-field")]
+mod hash_to_field;
+#[cfg(feature = "hash-to-field")]
+pub use hash_to_field::{hash_to_field, hash_to_field_xof, HashToField};
+
+#[cfg(feature = "hash-to-curve")]
+mod hash_to_curve;
+
+#[cfg(feature = "poseidon")]
+mod poseidon;
+#[cfg(feature = "poseidon")]
+pub use poseidon::{Poseidon, PoseidonField, Sponge};
+
+#[cfg(feature = "assigner")]
+mod assigner;
+#[cfg(feature = "assigner")]
+pub use assigner::{
+    from_assigner_json, to_assigner_json, AssignerError, AssignerErrorKind, AssignerValue,
+    CircuitInput, InputReader, JsonValue,
+};
+
+#[cfg(feature = "derive")]
+pub use zkllvm_rslang_types_derive::CircuitInput;
+#[cfg(feature = "macros")]
+pub use zkllvm_rslang_types_derive::field;
+
 #[cfg(feature = "hash")]
 mod hash;
 
@@ -235,6 +394,9 @@ mod iter;
 #[cfg(feature = "zeroize")]
 mod zeroize;
 
+#[cfg(feature = "serde")]
+mod serde;
+
 #[cfg(feature = "arkworks")]
 mod arkworks;
 #[cfg(feature = "arkworks")]
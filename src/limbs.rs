@@ -0,0 +1,187 @@
+//! Canonical little-endian limbs of field elements.
+//!
+//! With `software` backend limbs are taken directly from builtin types.
+//! `rslang` builtins do not expose their representation, so it is recovered with field
+//! arithmetic and comparisons instead. This relies on builtins being ordered by canonical
+//! value, which `software` builtins are as well, so the arithmetic versions are tested
+//! against the direct ones below.
+//!
+//! Limbs are not a part of public API, they are shared by the optional features.
+
+use super::{
+    Bls12381Base, Bls12381Scalar, Curve25519Base, Curve25519Scalar, PallasBase, PallasScalar,
+};
+#[cfg(any(not(feature = "software"), test))]
+use super::ZkField;
+
+/// Returns `true` if little-endian limbs `a` are less than `b` of the same length.
+#[cfg(any(
+    all(
+        not(feature = "software"),
+        any(feature = "int-conversions", feature = "repr", feature = "radix")
+    ),
+    test
+))]
+fn limbs_lt(a: &[u64], b: &[u64]) -> bool {
+    a.iter().rev().lt(b.iter().rev())
+}
+
+/// Recovers canonical little-endian limbs of `x` bit by bit, from the most significant one.
+///
+/// Takes `NUM_BITS` additions and comparisons, which are only correct
+/// if field elements are ordered by their canonical values.
+#[cfg(any(not(feature = "software"), test))]
+fn to_le_limbs_by_arithmetic<F: ZkField, const N: usize>(x: F, zero: F, one: F) -> [u64; N] {
+    let half = one / (one + one);
+    let mut pow = one;
+    for _ in 1..F::NUM_BITS {
+        pow += pow;
+    }
+    let mut acc = zero;
+    let mut limbs = [0u64; N];
+    for i in (0..F::NUM_BITS as usize).rev() {
+        // `acc + pow` wraps around the modulus only if it gets below `acc`.
+        let candidate = acc + pow;
+        if candidate >= acc && candidate <= x {
+            acc = candidate;
+            limbs[i / 64] |= 1 << (i % 64);
+        }
+        pow *= half;
+    }
+    limbs
+}
+
+/// Creates field element from little-endian limbs by double-and-add,
+/// starting from the most significant nonzero bit.
+///
+/// Returns `None` if the value is not less than the modulus.
+#[cfg(any(
+    all(
+        not(feature = "software"),
+        any(feature = "int-conversions", feature = "repr", feature = "radix")
+    ),
+    test
+))]
+fn from_le_limbs_by_arithmetic<F: ZkField>(limbs: &[u64], zero: F, one: F) -> Option<F> {
+    if !limbs_lt(limbs, F::MODULUS) {
+        return None;
+    }
+    let bits = limbs
+        .iter()
+        .rposition(|&limb| limb != 0)
+        .map_or(0, |i| 64 * i + 64 - limbs[i].leading_zeros() as usize);
+    let mut res = zero;
+    for i in (0..bits).rev() {
+        res += res;
+        if (limbs[i / 64] >> (i % 64)) & 1 == 1 {
+            res += one;
+        }
+    }
+    Some(res)
+}
+
+/// Implements limbs conversions on top of field arithmetic.
+#[cfg(not(feature = "software"))]
+macro_rules! limbs_impl {
+    ($($t:ident, $limbs:literal;)*) => ($(
+        impl $t {
+            /// Canonical little-endian limbs of the field element.
+            #[cfg(any(feature = "hash", feature = "repr", feature = "radix"))]
+            pub(crate) fn to_le_limbs(self) -> [u64; $limbs] {
+                let (zero, one) = (Self(builtin_zero!($t)), Self(builtin_one!($t)));
+                to_le_limbs_by_arithmetic(self, zero, one)
+            }
+
+            /// Creates field element from canonical little-endian limbs.
+            ///
+            /// Returns `None` if the value is not less than the modulus.
+            #[cfg(any(feature = "int-conversions", feature = "repr", feature = "radix"))]
+            pub(crate) fn from_le_limbs(limbs: [u64; $limbs]) -> Option<Self> {
+                let (zero, one) = (Self(builtin_zero!($t)), Self(builtin_one!($t)));
+                from_le_limbs_by_arithmetic(&limbs, zero, one)
+            }
+        }
+    )*)
+}
+
+/// Implements limbs conversions with builtin ones.
+#[cfg(feature = "software")]
+macro_rules! limbs_impl {
+    ($($t:ident, $limbs:literal;)*) => ($(
+        impl $t {
+            /// Canonical little-endian limbs of the field element.
+            #[cfg(any(feature = "hash", feature = "repr", feature = "radix", test))]
+            #[inline]
+            pub(crate) fn to_le_limbs(self) -> [u64; $limbs] {
+                self.0.to_le_limbs()
+            }
+
+            /// Creates field element from canonical little-endian limbs.
+            ///
+            /// Returns `None` if the value is not less than the modulus.
+            #[cfg(any(feature = "int-conversions", feature = "repr", feature = "radix", test))]
+            #[inline]
+            pub(crate) fn from_le_limbs(limbs: [u64; $limbs]) -> Option<Self> {
+                <$t as std::ops::Deref>::Target::from_le_limbs(limbs).map(Self)
+            }
+        }
+    )*)
+}
+
+limbs_impl! {
+    Bls12381Base, 6;
+    Bls12381Scalar, 4;
+    Curve25519Base, 4;
+    Curve25519Scalar, 4;
+    PallasBase, 4;
+    PallasScalar, 4;
+}
+
+#[cfg(all(test, feature = "software"))]
+mod tests {
+    use super::*;
+
+    /// Generates tests of arithmetic limbs conversions against builtin ones.
+    macro_rules! arithmetic_limbs_tests {
+        ($($name:ident, $t:ident;)*) => ($(
+            #[test]
+            fn $name() {
+                let zero = $t(builtin_zero!($t));
+                let one = $t(builtin_one!($t));
+
+                let mut values = vec![zero, one, -one, one + one, -(one + one)];
+                let mut x = <$t as ZkField>::ROOT_OF_UNITY;
+                for _ in 0..16 {
+                    values.push(x);
+                    x = x * x + <$t as ZkField>::MULTIPLICATIVE_GENERATOR;
+                }
+
+                for &x in &values {
+                    let limbs = x.to_le_limbs();
+                    assert_eq!(<$t>::from_le_limbs(limbs), Some(x));
+                    assert_eq!(to_le_limbs_by_arithmetic(x, zero, one), limbs);
+                    assert_eq!(from_le_limbs_by_arithmetic(&limbs, zero, one), Some(x));
+
+                    // The order of builtins is the order of canonical values.
+                    for &y in &values {
+                        assert_eq!(x < y, limbs_lt(&limbs, &y.to_le_limbs()));
+                    }
+                }
+
+                let modulus = <$t as ZkField>::MODULUS;
+                assert_eq!(from_le_limbs_by_arithmetic(modulus, zero, one), None);
+                let max = vec![u64::MAX; modulus.len()];
+                assert_eq!(from_le_limbs_by_arithmetic(&max, zero, one), None);
+            }
+        )*)
+    }
+
+    arithmetic_limbs_tests! {
+        bls12381_base_arithmetic_limbs, Bls12381Base;
+        bls12381_scalar_arithmetic_limbs, Bls12381Scalar;
+        curve25519_base_arithmetic_limbs, Curve25519Base;
+        curve25519_scalar_arithmetic_limbs, Curve25519Scalar;
+        pallas_base_arithmetic_limbs, PallasBase;
+        pallas_scalar_arithmetic_limbs, PallasScalar;
+    }
+}
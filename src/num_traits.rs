@@ -16,7 +16,7 @@ macro_rules! num_traits_impl {
         impl Zero for $t {
             #[inline]
             fn zero() -> Self {
-                Self(0g)
+                Self(builtin_zero!($t))
             }
 
             #[inline]
@@ -27,7 +27,7 @@ macro_rules! num_traits_impl {
 
         impl One for $t {
             fn one() -> Self {
-                Self(1g)
+                Self(builtin_one!($t))
             }
         }
     )*)
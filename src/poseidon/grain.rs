@@ -0,0 +1,90 @@
+//! Grain LFSR used by the reference implementation of Poseidon to generate parameters.
+
+use super::PoseidonField;
+
+/// Number of bits in the LFSR state.
+const STATE_BITS: u32 = 80;
+
+/// Self-shrinking Grain LFSR.
+pub(super) struct Grain {
+    /// State bits, the oldest one is the most significant.
+    state: u128,
+    /// Bit size of the field.
+    num_bits: u32,
+}
+
+impl Grain {
+    /// Initializes LFSR for prime field with given parameters,
+    /// `sbox` is the 4-bit S-box tag.
+    pub(super) fn new(
+        sbox: u8,
+        num_bits: u32,
+        width: usize,
+        full_rounds: usize,
+        partial_rounds: usize,
+    ) -> Self {
+        let mut state = 0u128;
+        for (value, len) in [
+            // Prime field.
+            (1, 2),
+            (sbox as u128, 4),
+            (num_bits as u128, 12),
+            (width as u128, 12),
+            (full_rounds as u128, 10),
+            (partial_rounds as u128, 10),
+            ((1 << 30) - 1, 30),
+        ] {
+            state = (state << len) | value;
+        }
+
+        let mut grain = Self { state, num_bits };
+        for _ in 0..160 {
+            grain.next_raw_bit();
+        }
+        grain
+    }
+
+    fn next_raw_bit(&mut self) -> bool {
+        let s = |i: u32| (self.state >> (STATE_BITS - 1 - i)) & 1;
+        let bit = s(62) ^ s(51) ^ s(38) ^ s(23) ^ s(13) ^ s(0);
+        self.state = ((self.state << 1) | bit) & ((1 << STATE_BITS) - 1);
+        bit == 1
+    }
+
+    /// Returns next output bit: of each pair of raw bits, the second one is output
+    /// only if the first one is set.
+    fn next_bit(&mut self) -> bool {
+        loop {
+            let keep = self.next_raw_bit();
+            let bit = self.next_raw_bit();
+            if keep {
+                return bit;
+            }
+        }
+    }
+
+    /// Returns next `num_bits` bits as little-endian bytes of big-endian bit string.
+    fn next_bytes(&mut self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        for i in (0..self.num_bits as usize).rev() {
+            if self.next_bit() {
+                bytes[i / 8] |= 1 << (i % 8);
+            }
+        }
+        bytes
+    }
+
+    /// Returns uniformly random field element, sampled by rejection.
+    pub(super) fn next_field_element<F: PoseidonField>(&mut self) -> F {
+        loop {
+            if let Some(elem) = F::from_bytes_le_checked(&self.next_bytes()) {
+                return elem;
+            }
+        }
+    }
+
+    /// Returns field element reduced modulo field modulus.
+    pub(super) fn next_field_element_without_rejection<F: PoseidonField>(&mut self) -> F {
+        F::from_bytes_le_reduced(&self.next_bytes())
+    }
+}
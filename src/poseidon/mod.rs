@@ -0,0 +1,391 @@
+//! Poseidon permutation and sponge with `x^5` S-box.
+//!
+//! Round constants and MDS matrices are generated with Grain LFSR the same way as
+//! the reference implementation of Poseidon does, so parameter sets match:
+//!
+//! * `P128Pow5T3` of `halo2` for `PallasBase` and `PallasScalar`:
+//!   width 3, 8 full and 56 partial rounds;
+//! * arity 2 instance of `neptune` used by Filecoin for `Bls12381Scalar`:
+//!   width 3, 8 full and 55 partial rounds, Cauchy MDS matrix over `0..2t`
+//!   and Grain S-box tag 1, which `neptune` uses for `x^5`.
+
+use super::{Bls12381Scalar, PallasBase, PallasScalar, ZkField};
+
+mod grain;
+
+use grain::Grain;
+
+/// Grain S-box tag of `x^alpha` used by the reference implementation and `halo2`.
+const SBOX_POW: u8 = 0;
+
+/// Grain S-box tag used by `neptune`, which is the reference tag of `x^(-1)`.
+const SBOX_NEPTUNE: u8 = 1;
+
+/// Field over which Poseidon can be instantiated.
+///
+/// Implemented only for fields with reference parameter sets checked against
+/// known answers: `PallasBase` and `PallasScalar` for `halo2`, `Bls12381Scalar` for `neptune`.
+pub trait PoseidonField: ZkField {
+    /// Additive identity.
+    const ZERO: Self;
+
+    /// Multiplicative identity.
+    const ONE: Self;
+
+    /// Creates field element from little-endian bytes of arbitrary length.
+    ///
+    /// Returns `None` if the encoded value is not less than the modulus.
+    fn from_bytes_le_checked(bytes: &[u8]) -> Option<Self>;
+
+    /// Creates field element from little-endian bytes of arbitrary length,
+    /// reducing the value modulo field modulus.
+    fn from_bytes_le_reduced(bytes: &[u8]) -> Self;
+
+    /// Creates field element from integer.
+    fn from_u128(value: u128) -> Self {
+        Self::from_bytes_le_reduced(&value.to_le_bytes())
+    }
+}
+
+/// Implements [`PoseidonField`].
+macro_rules! poseidon_field_impl {
+    ($($t:ident)*) => ($(
+        impl PoseidonField for $t {
+            const ZERO: Self = $t(builtin_zero!($t));
+
+            const ONE: Self = $t(builtin_one!($t));
+
+            fn from_bytes_le_checked(bytes: &[u8]) -> Option<Self> {
+                let (repr, rest) = bytes.split_at(bytes.len().min(Self::NUM_BYTES));
+                if rest.iter().any(|&b| b != 0) {
+                    return None;
+                }
+                let mut buf = [0u8; Self::NUM_BYTES];
+                buf[..repr.len()].copy_from_slice(repr);
+                Self::from_bytes_le(&buf)
+            }
+
+            #[inline]
+            fn from_bytes_le_reduced(bytes: &[u8]) -> Self {
+                Self::from_bytes_le_mod_order(bytes)
+            }
+        }
+    )*)
+}
+
+poseidon_field_impl! {
+    Bls12381Scalar
+    PallasBase
+    PallasScalar
+}
+
+/// Poseidon permutation of width `T` with `x^5` S-box.
+#[derive(Clone)]
+pub struct Poseidon<F, const T: usize> {
+    full_rounds: usize,
+    partial_rounds: usize,
+    round_constants: Vec<[F; T]>,
+    mds: [[F; T]; T],
+}
+
+impl<F: PoseidonField, const T: usize> Poseidon<F, T> {
+    /// Creates Poseidon permutation with given number of rounds, generating
+    /// round constants and MDS matrix with Grain LFSR.
+    ///
+    /// MDS matrix is a Cauchy matrix, `select` is the number of candidate matrices skipped,
+    /// as in `halo2`.
+    ///
+    /// Panics if `full_rounds` is odd.
+    pub fn new(full_rounds: usize, partial_rounds: usize, select: usize) -> Self {
+        let mut grain = Grain::new(SBOX_POW, F::NUM_BITS, T, full_rounds, partial_rounds);
+        let round_constants =
+            Self::generate_round_constants(&mut grain, full_rounds, partial_rounds);
+
+        let mut select = select;
+        let (xs, ys) = loop {
+            let xs: [F; T] = std::array::from_fn(|_| grain.next_field_element_without_rejection());
+            let ys: [F; T] = std::array::from_fn(|_| grain.next_field_element_without_rejection());
+
+            let distinct = xs
+                .iter()
+                .chain(&ys)
+                .enumerate()
+                .all(|(i, a)| xs.iter().chain(&ys).skip(i + 1).all(|b| a != b));
+            let invertible = xs.iter().all(|&x| ys.iter().all(|&y| x + y != F::ZERO));
+            if !distinct || !invertible {
+                continue;
+            }
+            if select == 0 {
+                break (xs, ys);
+            }
+            select -= 1;
+        };
+
+        Self {
+            full_rounds,
+            partial_rounds,
+            round_constants,
+            mds: Self::cauchy_matrix(&xs, &ys),
+        }
+    }
+
+    /// Creates Poseidon permutation with given number of rounds and MDS matrix,
+    /// generating round constants with Grain LFSR.
+    ///
+    /// Panics if `full_rounds` is odd.
+    pub fn with_mds(full_rounds: usize, partial_rounds: usize, mds: [[F; T]; T]) -> Self {
+        Self::with_mds_and_sbox(SBOX_POW, full_rounds, partial_rounds, mds)
+    }
+
+    fn with_mds_and_sbox(
+        sbox: u8,
+        full_rounds: usize,
+        partial_rounds: usize,
+        mds: [[F; T]; T],
+    ) -> Self {
+        let mut grain = Grain::new(sbox, F::NUM_BITS, T, full_rounds, partial_rounds);
+        Self {
+            full_rounds,
+            partial_rounds,
+            round_constants: Self::generate_round_constants(
+                &mut grain,
+                full_rounds,
+                partial_rounds,
+            ),
+            mds,
+        }
+    }
+
+    fn generate_round_constants(
+        grain: &mut Grain,
+        full_rounds: usize,
+        partial_rounds: usize,
+    ) -> Vec<[F; T]> {
+        assert!(full_rounds & 1 == 0, "number of full rounds must be even");
+        (0..full_rounds + partial_rounds)
+            .map(|_| std::array::from_fn(|_| grain.next_field_element()))
+            .collect()
+    }
+
+    /// Returns Cauchy matrix `m[i][j] = 1 / (x_i + y_j)`.
+    fn cauchy_matrix(xs: &[F; T], ys: &[F; T]) -> [[F; T]; T] {
+        std::array::from_fn(|i| std::array::from_fn(|j| F::ONE / (xs[i] + ys[j])))
+    }
+
+    /// Number of full rounds.
+    pub fn full_rounds(&self) -> usize {
+        self.full_rounds
+    }
+
+    /// Number of partial rounds.
+    pub fn partial_rounds(&self) -> usize {
+        self.partial_rounds
+    }
+
+    /// Round constants, one array per round.
+    pub fn round_constants(&self) -> &[[F; T]] {
+        &self.round_constants
+    }
+
+    /// MDS matrix.
+    pub fn mds(&self) -> &[[F; T]; T] {
+        &self.mds
+    }
+
+    /// Applies the permutation to the state in place.
+    pub fn permute(&self, state: &mut [F; T]) {
+        let sbox = |x: F| {
+            let x2 = x * x;
+            x2 * x2 * x
+        };
+
+        let half_full = self.full_rounds / 2;
+        for (round, constants) in self.round_constants.iter().enumerate() {
+            for (s, &c) in state.iter_mut().zip(constants) {
+                *s += c;
+            }
+
+            if round < half_full || round >= half_full + self.partial_rounds {
+                for s in state.iter_mut() {
+                    *s = sbox(*s);
+                }
+            } else {
+                state[0] = sbox(state[0]);
+            }
+
+            let prev = *state;
+            for (s, row) in state.iter_mut().zip(&self.mds) {
+                *s = row
+                    .iter()
+                    .zip(&prev)
+                    .fold(F::ZERO, |acc, (&m, &x)| acc + m * x);
+            }
+        }
+    }
+
+    /// Hashes message of fixed length with the sponge of rate `T - 1`, as `ConstantLength`
+    /// domain of `halo2` does.
+    ///
+    /// Capacity element is initialized with `len * 2^64`, the message is padded with zeros
+    /// to a multiple of the rate, and the first element of the state is squeezed.
+    pub fn hash(&self, message: &[F]) -> F {
+        let rate = T - 1;
+        let mut sponge = Sponge::new(self, F::from_u128((message.len() as u128) << 64));
+        for &elem in message {
+            sponge.absorb(elem);
+        }
+        for _ in 0..(rate - message.len() % rate) % rate {
+            sponge.absorb(F::ZERO);
+        }
+        sponge.squeeze()
+    }
+
+    /// Hashes `T - 1` elements as `neptune` does for Merkle trees of arity `T - 1`.
+    ///
+    /// The first element of the state is set to domain tag `2^(T - 1) - 1`, followed by
+    /// the preimage, and the second element of the permuted state is returned.
+    ///
+    /// Panics if length of `preimage` is not `T - 1`.
+    pub fn hash_merkle(&self, preimage: &[F]) -> F {
+        assert_eq!(preimage.len(), T - 1, "preimage length must be equal to arity");
+        let mut state = [F::from_u128((1 << (T - 1)) - 1); T];
+        state[1..].copy_from_slice(preimage);
+        self.permute(&mut state);
+        state[1]
+    }
+}
+
+/// Implements `halo2` parameter sets.
+macro_rules! poseidon_halo2_impl {
+    ($($t:ty)*) => ($(
+        impl Poseidon<$t, 3> {
+            /// Creates permutation of `P128Pow5T3` specification of `halo2`.
+            pub fn p128_pow5_t3() -> Self {
+                Self::new(8, 56, 0)
+            }
+        }
+    )*)
+}
+
+poseidon_halo2_impl! {
+    PallasBase
+    PallasScalar
+}
+
+impl Poseidon<Bls12381Scalar, 3> {
+    /// Creates permutation used by `neptune` for Merkle trees of arity 2.
+    ///
+    /// Round constants are generated with S-box tag of `neptune`, so they differ from
+    /// the ones of [`Poseidon::with_mds`].
+    pub fn neptune_arity_2() -> Self {
+        let xs = [0, 1, 2].map(Bls12381Scalar::from_u128);
+        let ys = [3, 4, 5].map(Bls12381Scalar::from_u128);
+        Self::with_mds_and_sbox(SBOX_NEPTUNE, 8, 55, Self::cauchy_matrix(&xs, &ys))
+    }
+}
+
+/// Sponge over Poseidon permutation with capacity 1 and rate `T - 1`.
+///
+/// The capacity element is the last one of the state.
+/// Absorbing after squeezing starts absorbing anew, without resetting the state.
+#[derive(Clone)]
+pub struct Sponge<'a, F, const T: usize> {
+    poseidon: &'a Poseidon<F, T>,
+    state: [F; T],
+    pos: usize,
+    squeezing: bool,
+}
+
+impl<'a, F: PoseidonField, const T: usize> Sponge<'a, F, T> {
+    /// Creates sponge with given initial value of the capacity element.
+    pub fn new(poseidon: &'a Poseidon<F, T>, capacity: F) -> Self {
+        let mut state = [F::ZERO; T];
+        state[T - 1] = capacity;
+        Self {
+            poseidon,
+            state,
+            pos: 0,
+            squeezing: false,
+        }
+    }
+
+    /// Absorbs single field element.
+    pub fn absorb(&mut self, elem: F) {
+        if self.squeezing {
+            self.squeezing = false;
+            self.pos = 0;
+        }
+        if self.pos == T - 1 {
+            self.poseidon.permute(&mut self.state);
+            self.pos = 0;
+        }
+        self.state[self.pos] += elem;
+        self.pos += 1;
+    }
+
+    /// Squeezes single field element.
+    pub fn squeeze(&mut self) -> F {
+        if !self.squeezing || self.pos == T - 1 {
+            self.poseidon.permute(&mut self.state);
+            self.squeezing = true;
+            self.pos = 0;
+        }
+        let elem = self.state[self.pos];
+        self.pos += 1;
+        elem
+    }
+}
+
+#[cfg(all(test, feature = "software"))]
+mod tests {
+    use super::*;
+
+    /// Generates known answer tests of `P128Pow5T3` from `halo2` test vectors.
+    macro_rules! halo2_tests {
+        ($($name:ident: $t:ident, $permuted:expr, $hash:expr;)*) => ($(
+            #[test]
+            fn $name() {
+                let poseidon = Poseidon::<$t, 3>::p128_pow5_t3();
+
+                let mut state = [0, 1, 2].map($t::from_u128);
+                poseidon.permute(&mut state);
+                assert_eq!(state, $permuted.map(|limbs| $t::from_le_limbs(limbs).unwrap()));
+
+                let message = [0, 1].map($t::from_u128);
+                assert_eq!(poseidon.hash(&message), $t::from_le_limbs($hash).unwrap());
+            }
+        )*)
+    }
+
+    halo2_tests! {
+        p128_pow5_t3_pallas_base: PallasBase,
+            [
+                [0xaeb1bc024aeca456, 0xf7e69a71d0b642a0, 0x94efb364f966240f, 0x2a526acd0b64b453],
+                [0x012a3e9628e5b82a, 0xdcd42e7fbed9dafe, 0x76ff7dae343d5512, 0x13c5d1568b4aa430],
+                [0x359029a1d34e9ddd, 0xf7cfdfe1bda42c7b, 0x256fcd597984561a, 0x0a49c868c6976544],
+            ],
+            [0x389d32a011d75883, 0xed83c2a7fb54cdbe, 0x9d6a1bc9399a083e, 0x062ff1c32bb0ef10];
+        p128_pow5_t3_pallas_scalar: PallasScalar,
+            [
+                [0x0eb08ea813bebe59, 0x4d43d1973dd336c6, 0xeddd74f22f8f2ff7, 0x315a1f4cdb942f7c],
+                [0xf9f126e61ea165f1, 0x413ee0eb7bbd2198, 0x642adee0dd13aa48, 0x3be475f2d7642bde],
+                [0x14d542372a7ba0d9, 0x5019bfd4e0423fa0, 0x117fdb2420d8ea60, 0x25ab8aece9537168],
+            ],
+            [0xf357297085f6684e, 0x4e3101097a6b54bf, 0x4664b1e35e194f51, 0x15ba96df939d7722];
+    }
+
+    // `hash_values` test of `neptune` with standard strength.
+    #[test]
+    fn neptune_arity_2() {
+        let poseidon = Poseidon::<Bls12381Scalar, 3>::neptune_arity_2();
+        let preimage = [0, 1].map(Bls12381Scalar::from_u128);
+        let expected = Bls12381Scalar::from_le_limbs([
+            0x2e203c369a02e7ff,
+            0xa6fba9339d05a69d,
+            0x739e0fd902efe161,
+            0x396508d75e76a56b,
+        ])
+        .unwrap();
+        assert_eq!(poseidon.hash_merkle(&preimage), expected);
+    }
+}
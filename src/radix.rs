@@ -0,0 +1,214 @@
+//! Parsing and radix formatting of field elements.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use super::{
+    Bls12381Base, Bls12381Scalar, Curve25519Base, Curve25519Scalar, PallasBase, PallasScalar,
+};
+
+/// Error returned when parsing a field element from string fails.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ParseFieldError {
+    /// The string has no digits.
+    Empty,
+    /// The string contains a character which is not a digit.
+    InvalidDigit,
+    /// The value is not less than the field modulus.
+    OutOfRange,
+}
+
+impl fmt::Display for ParseFieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseFieldError::Empty => f.write_str("cannot parse field element from empty string"),
+            ParseFieldError::InvalidDigit => f.write_str("invalid digit found in string"),
+            ParseFieldError::OutOfRange => f.write_str("value is not less than the field modulus"),
+        }
+    }
+}
+
+impl Error for ParseFieldError {}
+
+/// Parses decimal or `0x`-prefixed hexadecimal unsigned integer into little-endian limbs.
+fn parse_le_limbs<const N: usize>(s: &str) -> Result<[u64; N], ParseFieldError> {
+    let (digits, radix) = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => (hex, 16),
+        None => (s, 10),
+    };
+    if digits.is_empty() {
+        return Err(ParseFieldError::Empty);
+    }
+
+    let mut limbs = [0u64; N];
+    for c in digits.chars() {
+        let mut carry = c.to_digit(radix).ok_or(ParseFieldError::InvalidDigit)? as u128;
+        for limb in limbs.iter_mut() {
+            let acc = *limb as u128 * radix as u128 + carry;
+            *limb = acc as u64;
+            carry = acc >> 64;
+        }
+        if carry != 0 {
+            return Err(ParseFieldError::OutOfRange);
+        }
+    }
+    Ok(limbs)
+}
+
+/// Writes little-endian limbs without leading zeros, with `bits` bits per digit.
+fn write_limbs(
+    limbs: &[u64],
+    f: &mut fmt::Formatter<'_>,
+    prefix: &str,
+    bits: u32,
+    upper: bool,
+) -> fmt::Result {
+    let mut digits = String::new();
+    for limb in limbs.iter().rev() {
+        for i in (0..64 / bits).rev() {
+            let digit = (limb >> (i * bits)) & ((1 << bits) - 1);
+            if digit != 0 || !digits.is_empty() {
+                let c = char::from_digit(digit as u32, 1 << bits).unwrap();
+                digits.push(if upper { c.to_ascii_uppercase() } else { c });
+            }
+        }
+    }
+    if digits.is_empty() {
+        digits.push('0');
+    }
+    f.pad_integral(true, prefix, &digits)
+}
+
+/// Implements [`FromStr`], [`fmt::LowerHex`], [`fmt::UpperHex`] and [`fmt::Binary`].
+macro_rules! radix_impl {
+    ($($t:ty, $limbs:literal;)*) => ($(
+        impl FromStr for $t {
+            type Err = ParseFieldError;
+
+            /// Parses decimal or `0x`-prefixed hexadecimal representation of the field element.
+            ///
+            /// Values not less than the modulus are rejected.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::from_le_limbs(parse_le_limbs::<$limbs>(s)?).ok_or(ParseFieldError::OutOfRange)
+            }
+        }
+
+        impl fmt::LowerHex for $t {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write_limbs(&self.to_le_limbs(), f, "0x", 4, false)
+            }
+        }
+
+        impl fmt::UpperHex for $t {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write_limbs(&self.to_le_limbs(), f, "0x", 4, true)
+            }
+        }
+
+        impl fmt::Binary for $t {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write_limbs(&self.to_le_limbs(), f, "0b", 1, false)
+            }
+        }
+    )*)
+}
+
+radix_impl! {
+    Bls12381Base, 6;
+    Bls12381Scalar, 4;
+    Curve25519Base, 4;
+    Curve25519Scalar, 4;
+    PallasBase, 4;
+    PallasScalar, 4;
+}
+
+#[cfg(all(test, feature = "software"))]
+mod tests {
+    use super::*;
+    use crate::ZkField;
+    use num_bigint::BigUint;
+
+    fn to_biguint(limbs: &[u64]) -> BigUint {
+        let digits: Vec<u32> = limbs.iter().flat_map(|&l| [l as u32, (l >> 32) as u32]).collect();
+        BigUint::from_slice(&digits)
+    }
+
+    /// Generates tests of parsing and formatting against `num-bigint`.
+    macro_rules! radix_tests {
+        ($($name:ident, $t:ident;)*) => ($(
+            #[test]
+            fn $name() {
+                let zero = $t(builtin_zero!($t));
+                let one = $t(builtin_one!($t));
+                let modulus = to_biguint(<$t as ZkField>::MODULUS);
+
+                let mut x = <$t as ZkField>::ROOT_OF_UNITY;
+                for value in [zero, one, -one, one + one] {
+                    for x in [value, x] {
+                        let digits = to_biguint(&x.to_le_limbs());
+                        let hex = digits.to_str_radix(16);
+                        assert_eq!(format!("{:x}", x), hex);
+                        assert_eq!(format!("{:X}", x), hex.to_uppercase());
+                        assert_eq!(format!("{:#x}", x), format!("0x{}", hex));
+                        assert_eq!(format!("{:b}", x), digits.to_str_radix(2));
+
+                        assert_eq!(digits.to_string().parse::<$t>(), Ok(x));
+                        assert_eq!(format!("{:#x}", x).parse::<$t>(), Ok(x));
+                        assert_eq!(format!("0X{:X}", x).parse::<$t>(), Ok(x));
+                        assert_eq!(format!("000{}", digits).parse::<$t>(), Ok(x));
+                    }
+                    x = x * x + <$t as ZkField>::MULTIPLICATIVE_GENERATOR;
+                }
+                assert_eq!(format!("{:#06x}", zero), "0x0000");
+                assert_eq!(format!("{:>4b}", one + one), "  10");
+
+                assert_eq!("".parse::<$t>(), Err(ParseFieldError::Empty));
+                assert_eq!("0x".parse::<$t>(), Err(ParseFieldError::Empty));
+                assert_eq!("-1".parse::<$t>(), Err(ParseFieldError::InvalidDigit));
+                assert_eq!("+1".parse::<$t>(), Err(ParseFieldError::InvalidDigit));
+                assert_eq!(" 1".parse::<$t>(), Err(ParseFieldError::InvalidDigit));
+                assert_eq!("1_000".parse::<$t>(), Err(ParseFieldError::InvalidDigit));
+                assert_eq!("0xfg".parse::<$t>(), Err(ParseFieldError::InvalidDigit));
+                assert_eq!("ff".parse::<$t>(), Err(ParseFieldError::InvalidDigit));
+                assert_eq!("0b1".parse::<$t>(), Err(ParseFieldError::InvalidDigit));
+
+                let p_minus_one = &modulus - 1u8;
+                assert_eq!(p_minus_one.to_string().parse::<$t>(), Ok(-one));
+                assert_eq!(
+                    format!("0x{}", p_minus_one.to_str_radix(16)).parse::<$t>(),
+                    Ok(-one),
+                );
+                for above in [modulus.clone(), &modulus + 1u8, &modulus << 1u8] {
+                    assert_eq!(above.to_string().parse::<$t>(), Err(ParseFieldError::OutOfRange));
+                    let hex = format!("0x{}", above.to_str_radix(16));
+                    assert_eq!(hex.parse::<$t>(), Err(ParseFieldError::OutOfRange));
+                }
+
+                // Values which do not fit into limbs at all.
+                let overflow = BigUint::from(1u8) << (64 * <$t as ZkField>::MODULUS.len());
+                assert_eq!(overflow.to_string().parse::<$t>(), Err(ParseFieldError::OutOfRange));
+                let long = "9".repeat(200);
+                assert_eq!(long.parse::<$t>(), Err(ParseFieldError::OutOfRange));
+            }
+        )*)
+    }
+
+    radix_tests! {
+        bls12381_base_radix, Bls12381Base;
+        bls12381_scalar_radix, Bls12381Scalar;
+        curve25519_base_radix, Curve25519Base;
+        curve25519_scalar_radix, Curve25519Scalar;
+        pallas_base_radix, PallasBase;
+        pallas_scalar_radix, PallasScalar;
+    }
+
+    #[test]
+    fn invalid_digit_after_overflow() {
+        // Digits are checked while parsing, so the first error found wins.
+        let s = format!("{}x", "9".repeat(200));
+        assert_eq!(s.parse::<PallasBase>(), Err(ParseFieldError::OutOfRange));
+        let s = format!("x{}", "9".repeat(200));
+        assert_eq!(s.parse::<PallasBase>(), Err(ParseFieldError::InvalidDigit));
+    }
+}
@@ -0,0 +1,218 @@
+//! Canonical integer representation of field elements: bytes and bits.
+
+use super::{
+    Bls12381Base, Bls12381Scalar, Curve25519Base, Curve25519Scalar, PallasBase, PallasScalar,
+};
+
+/// Implements canonical representation methods.
+macro_rules! repr_impl {
+    ($($t:ty, $limbs:literal, $bytes:literal;)*) => ($(
+        impl $t {
+            /// Size of canonical byte encoding.
+            pub(crate) const NUM_BYTES: usize = $bytes;
+
+            /// Returns canonical little-endian byte encoding of the field element.
+            pub fn to_bytes_le(&self) -> [u8; $bytes] {
+                let mut bytes = [0u8; Self::NUM_BYTES];
+                for (chunk, limb) in bytes.chunks_mut(8).zip(self.to_le_limbs()) {
+                    chunk.copy_from_slice(&limb.to_le_bytes());
+                }
+                bytes
+            }
+
+            /// Returns canonical big-endian byte encoding of the field element.
+            pub fn to_bytes_be(&self) -> [u8; $bytes] {
+                let mut bytes = self.to_bytes_le();
+                bytes.reverse();
+                bytes
+            }
+
+            /// Creates field element from canonical little-endian byte encoding.
+            ///
+            /// Returns `None` if the encoded value is not less than the modulus.
+            pub fn from_bytes_le(bytes: &[u8; $bytes]) -> Option<Self> {
+                let mut limbs = [0u64; $limbs];
+                for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks(8)) {
+                    *limb = u64::from_le_bytes(chunk.try_into().unwrap());
+                }
+                Self::from_le_limbs(limbs)
+            }
+
+            /// Creates field element from little-endian bytes of arbitrary length,
+            /// reducing the value modulo field modulus.
+            pub fn from_bytes_le_mod_order(bytes: &[u8]) -> Self {
+                // Chunks of whole bytes shorter than the modulus are always canonical.
+                const CHUNK: usize = (<$t>::NUM_BITS as usize - 1) / 8;
+
+                let mut shift = Self(builtin_one!($t));
+                for _ in 0..8 * CHUNK {
+                    shift += shift;
+                }
+
+                let mut res = Self(builtin_zero!($t));
+                for chunk in bytes.chunks(CHUNK).rev() {
+                    let mut buf = [0u8; Self::NUM_BYTES];
+                    buf[..chunk.len()].copy_from_slice(chunk);
+                    res = res * shift + Self::from_bytes_le(&buf).unwrap();
+                }
+                res
+            }
+
+            /// Returns little-endian bits of canonical representation of the field element.
+            pub fn to_bits_le(&self) -> [bool; <$t>::NUM_BITS as usize] {
+                let limbs = self.to_le_limbs();
+                std::array::from_fn(|i| (limbs[i / 64] >> (i % 64)) & 1 == 1)
+            }
+
+            /// Returns big-endian bits of canonical representation of the field element.
+            pub fn to_bits_be(&self) -> [bool; <$t>::NUM_BITS as usize] {
+                let mut bits = self.to_bits_le();
+                bits.reverse();
+                bits
+            }
+
+            /// Creates field element from little-endian bits of canonical representation.
+            ///
+            /// Returns `None` if the encoded value is not less than the modulus.
+            pub fn from_bits_le(bits: &[bool; <$t>::NUM_BITS as usize]) -> Option<Self> {
+                let mut limbs = [0u64; $limbs];
+                for (i, &bit) in bits.iter().enumerate() {
+                    limbs[i / 64] |= (bit as u64) << (i % 64);
+                }
+                Self::from_le_limbs(limbs)
+            }
+        }
+    )*)
+}
+
+repr_impl! {
+    Bls12381Base, 6, 48;
+    Bls12381Scalar, 4, 32;
+    Curve25519Base, 4, 32;
+    Curve25519Scalar, 4, 32;
+    PallasBase, 4, 32;
+    PallasScalar, 4, 32;
+}
+
+#[cfg(all(test, feature = "software"))]
+mod tests {
+    use super::*;
+    use crate::ZkField;
+    use num_bigint::BigUint;
+
+    /// Generates tests of bytes and bits representations.
+    macro_rules! repr_tests {
+        ($($name:ident, $t:ident;)*) => ($(
+            #[test]
+            fn $name() {
+                let one = $t(builtin_one!($t));
+                let modulus = <$t as ZkField>::MODULUS;
+
+                let mut limbs = [0u64; <$t as ZkField>::MODULUS.len()];
+                limbs[0] = 0x1234;
+                let small = <$t>::from_le_limbs(limbs).unwrap();
+                assert_eq!(small.to_bytes_le()[..3], [0x34, 0x12, 0]);
+                assert_eq!(small.to_bytes_be()[<$t>::NUM_BYTES - 3..], [0, 0x12, 0x34]);
+
+                let mut x = <$t as ZkField>::ROOT_OF_UNITY;
+                for value in [-one, small, one, -(one + one)] {
+                    for x in [value, x] {
+                        let bytes = x.to_bytes_le();
+                        assert_eq!(<$t>::from_bytes_le(&bytes), Some(x));
+                        let mut be = x.to_bytes_be();
+                        be.reverse();
+                        assert_eq!(be, bytes);
+
+                        let bits = x.to_bits_le();
+                        assert_eq!(<$t>::from_bits_le(&bits), Some(x));
+                        let mut be = x.to_bits_be();
+                        be.reverse();
+                        assert_eq!(be, bits);
+                        for (i, &bit) in bits.iter().enumerate() {
+                            assert_eq!(bit, (bytes[i / 8] >> (i % 8)) & 1 == 1);
+                        }
+                    }
+                    x = x * x + <$t as ZkField>::MULTIPLICATIVE_GENERATOR;
+                }
+
+                // `p - 1` is the largest canonical value.
+                let modulus_le: Vec<u8> = modulus.iter().flat_map(|l| l.to_le_bytes()).collect();
+                let mut bytes: [u8; <$t>::NUM_BYTES] = modulus_le.try_into().unwrap();
+                assert_eq!(<$t>::from_bytes_le(&bytes), None);
+                bytes[0] -= 1;
+                assert_eq!(<$t>::from_bytes_le(&bytes), Some(-one));
+                assert_eq!(<$t>::from_bytes_le(&[u8::MAX; <$t>::NUM_BYTES]), None);
+
+                let mut bits = [false; <$t>::NUM_BITS as usize];
+                for (i, bit) in bits.iter_mut().enumerate() {
+                    *bit = (modulus[i / 64] >> (i % 64)) & 1 == 1;
+                }
+                assert_eq!(<$t>::from_bits_le(&bits), None);
+                bits[0] = false;
+                assert_eq!(<$t>::from_bits_le(&bits), Some(-one));
+                assert_eq!(<$t>::from_bits_le(&[true; <$t>::NUM_BITS as usize]), None);
+
+                let modulus = BigUint::from_bytes_le(&bytes) + 1u8;
+                let mut bytes = Vec::new();
+                for len in [0, 1, 31, 32, 33, 47, 48, 49, 64, 96, 128, 200] {
+                    while bytes.len() < len {
+                        bytes.push((bytes.len() as u8).wrapping_mul(167).wrapping_add(13));
+                    }
+                    let reduced = (BigUint::from_bytes_le(&bytes) % &modulus).to_bytes_le();
+                    let mut expected = [0u8; <$t>::NUM_BYTES];
+                    expected[..reduced.len()].copy_from_slice(&reduced);
+                    let x = <$t>::from_bytes_le_mod_order(&bytes);
+                    assert_eq!(x.to_bytes_le(), expected, "{} bytes", len);
+                }
+                let max = [u8::MAX; 100];
+                let reduced = (BigUint::from_bytes_le(&max) % &modulus).to_bytes_le();
+                let x = <$t>::from_bytes_le_mod_order(&max).to_bytes_le();
+                assert_eq!(x[..reduced.len()], reduced[..]);
+            }
+        )*)
+    }
+
+    repr_tests! {
+        bls12381_base_repr, Bls12381Base;
+        bls12381_scalar_repr, Bls12381Scalar;
+        curve25519_base_repr, Curve25519Base;
+        curve25519_scalar_repr, Curve25519Scalar;
+        pallas_base_repr, PallasBase;
+        pallas_scalar_repr, PallasScalar;
+    }
+
+    /// Generates tests of bit order with powers of two.
+    macro_rules! bit_order_tests {
+        ($($name:ident, $t:ident;)*) => ($(
+            #[test]
+            fn $name() {
+                const BITS: usize = <$t>::NUM_BITS as usize;
+                let zero = $t(builtin_zero!($t));
+                assert_eq!(zero.to_bits_le(), [false; BITS]);
+                assert_eq!(<$t>::from_bits_le(&[false; BITS]), Some(zero));
+
+                // Every power of two below the modulus has a single bit set.
+                let mut pow = $t(builtin_one!($t));
+                for k in 0..BITS {
+                    let le = pow.to_bits_le();
+                    let be = pow.to_bits_be();
+                    for i in 0..BITS {
+                        assert_eq!(le[i], i == k, "2^{}", k);
+                        assert_eq!(be[i], i == BITS - 1 - k, "2^{}", k);
+                    }
+                    assert_eq!(<$t>::from_bits_le(&le), Some(pow));
+                    pow += pow;
+                }
+            }
+        )*)
+    }
+
+    bit_order_tests! {
+        bls12381_base_bit_order, Bls12381Base;
+        bls12381_scalar_bit_order, Bls12381Scalar;
+        curve25519_base_bit_order, Curve25519Base;
+        curve25519_scalar_bit_order, Curve25519Scalar;
+        pallas_base_bit_order, PallasBase;
+        pallas_scalar_bit_order, PallasScalar;
+    }
+}
@@ -0,0 +1,233 @@
+//! [`Serialize`] and [`Deserialize`] implementations.
+//!
+//! Human-readable formats encode field elements as `0x`-prefixed big-endian hexadecimal
+//! strings, decimal strings are accepted as well. Binary formats use canonical little-endian
+//! byte encoding of fixed size.
+//!
+//! Curve elements are encoded as pairs of affine coordinates in human-readable formats,
+//! with neutral element of a short Weierstrass curve being `(0, 0)`,
+//! and in compressed form in binary formats.
+//! Deserialized curve elements are checked to be points of the prime-order subgroup.
+//! Curve elements are serialized and deserialized only with `software` backend,
+//! since `rslang` builtins do not expose their coordinates.
+
+use std::fmt::{self, Write};
+
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+use serde::ser::{SerializeTuple, Serializer};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    Bls12381Base, Bls12381Scalar, Curve25519Base, Curve25519Scalar, PallasBase, PallasScalar,
+    ParseFieldError,
+};
+#[cfg(feature = "software")]
+use super::{Bls12381, Curve25519, Pallas, Vesta, VestaBase};
+
+/// Serializes fixed-size byte array as a tuple, so that no length is written.
+fn serialize_byte_array<S: Serializer, const N: usize>(
+    bytes: &[u8; N],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut tuple = serializer.serialize_tuple(N)?;
+    for byte in bytes {
+        tuple.serialize_element(byte)?;
+    }
+    tuple.end()
+}
+
+/// Visitor of fixed-size byte array.
+struct ByteArrayVisitor<const N: usize>;
+
+impl<'de, const N: usize> Visitor<'de> for ByteArrayVisitor<N> {
+    type Value = [u8; N];
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} bytes", N)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        v.try_into().map_err(|_| E::invalid_length(v.len(), &self))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = [0u8; N];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        Ok(bytes)
+    }
+}
+
+/// Deserializes fixed-size byte array serialized with [`serialize_byte_array`].
+fn deserialize_byte_array<'de, D: Deserializer<'de>, const N: usize>(
+    deserializer: D,
+) -> Result<[u8; N], D::Error> {
+    deserializer.deserialize_tuple(N, ByteArrayVisitor::<N>)
+}
+
+/// Implements [`Serialize`] and [`Deserialize`] for field wrappers.
+macro_rules! field_serde_impl {
+    ($($t:ty)*) => ($(
+        impl Serialize for $t {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    let mut hex = String::from("0x");
+                    for byte in self.to_bytes_be() {
+                        write!(hex, "{:02x}", byte).unwrap();
+                    }
+                    serializer.serialize_str(&hex)
+                } else {
+                    serialize_byte_array(&self.to_bytes_le(), serializer)
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $t {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                if deserializer.is_human_readable() {
+                    String::deserialize(deserializer)?
+                        .parse()
+                        .map_err(de::Error::custom)
+                } else {
+                    let bytes = deserialize_byte_array(deserializer)?;
+                    Self::from_bytes_le(&bytes)
+                        .ok_or_else(|| de::Error::custom(ParseFieldError::OutOfRange))
+                }
+            }
+        }
+    )*)
+}
+
+field_serde_impl! {
+    Bls12381Base
+    Bls12381Scalar
+    Curve25519Base
+    Curve25519Scalar
+    PallasBase
+    PallasScalar
+}
+
+/// Implements [`Serialize`] and [`Deserialize`] for curve wrappers.
+#[cfg(feature = "software")]
+macro_rules! curve_serde_impl {
+    ($($curve:ty, $base:ty;)*) => ($(
+        impl Serialize for $curve {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    (<$base>::from(self.0.x()), <$base>::from(self.0.y())).serialize(serializer)
+                } else {
+                    serialize_byte_array(&self.to_compressed(), serializer)
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $curve {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                if deserializer.is_human_readable() {
+                    let (x, y) = <($base, $base)>::deserialize(deserializer)?;
+                    Self::try_from_affine(x, y).map_err(de::Error::custom)
+                } else {
+                    let bytes = deserialize_byte_array(deserializer)?;
+                    Self::from_compressed(&bytes).map_err(de::Error::custom)
+                }
+            }
+        }
+    )*)
+}
+
+#[cfg(feature = "software")]
+curve_serde_impl! {
+    Bls12381, Bls12381Base;
+    Curve25519, Curve25519Base;
+    Pallas, PallasBase;
+    Vesta, VestaBase;
+}
+
+#[cfg(all(test, feature = "software"))]
+mod tests {
+    use super::*;
+    use crate::ZkField;
+
+    /// Generates round-trip tests of field elements in JSON and bincode.
+    macro_rules! field_serde_tests {
+        ($($name:ident, $t:ident;)*) => ($(
+            #[test]
+            fn $name() {
+                let one = $t(builtin_one!($t));
+                let mut x = <$t as ZkField>::ROOT_OF_UNITY;
+                for value in [$t(builtin_zero!($t)), one, -one] {
+                    for x in [value, x] {
+                        let json = serde_json::to_string(&x).unwrap();
+                        let width = 2 * <$t>::NUM_BYTES;
+                        assert_eq!(json, format!("\"0x{:0width$x}\"", x, width = width));
+                        assert_eq!(serde_json::from_str::<$t>(&json).unwrap(), x);
+
+                        let bytes = bincode::serialize(&x).unwrap();
+                        assert_eq!(bytes, x.to_bytes_le());
+                        assert_eq!(bincode::deserialize::<$t>(&bytes).unwrap(), x);
+                    }
+                    x = x * x + <$t as ZkField>::MULTIPLICATIVE_GENERATOR;
+                }
+                assert_eq!(serde_json::from_str::<$t>("\"2\"").unwrap(), one + one);
+
+                let modulus: Vec<u8> = <$t as ZkField>::MODULUS
+                    .iter()
+                    .flat_map(|limb| limb.to_le_bytes())
+                    .collect();
+                let hex: String = modulus.iter().rev().map(|b| format!("{:02x}", b)).collect();
+                assert!(serde_json::from_str::<$t>(&format!("\"0x{}\"", hex)).is_err());
+                let decimal = format!("\"{}\"", <$t as ZkField>::MODULUS_STR);
+                assert!(serde_json::from_str::<$t>(&decimal).is_err());
+                assert!(serde_json::from_str::<$t>("\"\"").is_err());
+                assert!(serde_json::from_str::<$t>("1").is_err());
+                assert!(bincode::deserialize::<$t>(&modulus).is_err());
+                assert!(bincode::deserialize::<$t>(&modulus[1..]).is_err());
+            }
+        )*)
+    }
+
+    field_serde_tests! {
+        bls12381_base_serde, Bls12381Base;
+        bls12381_scalar_serde, Bls12381Scalar;
+        curve25519_base_serde, Curve25519Base;
+        curve25519_scalar_serde, Curve25519Scalar;
+        pallas_base_serde, PallasBase;
+        pallas_scalar_serde, PallasScalar;
+    }
+
+    /// Generates round-trip tests of curve elements in JSON and bincode.
+    macro_rules! curve_serde_tests {
+        ($($name:ident, $curve:ty, $base:ident;)*) => ($(
+            #[test]
+            fn $name() {
+                let g = <$curve>::one();
+                let mut p = <$curve>::zero();
+                for _ in 0..4 {
+                    let json = serde_json::to_string(&p).unwrap();
+                    let coordinates = ($base(p.0.x()), $base(p.0.y()));
+                    assert_eq!(json, serde_json::to_string(&coordinates).unwrap());
+                    assert_eq!(serde_json::from_str::<$curve>(&json).unwrap(), p);
+
+                    let bytes = bincode::serialize(&p).unwrap();
+                    assert_eq!(bytes, p.to_compressed());
+                    assert_eq!(bincode::deserialize::<$curve>(&bytes).unwrap(), p);
+                    p += g;
+                }
+
+                let one = $base(builtin_one!($base));
+                let off_curve = serde_json::to_string(&(one, one + one)).unwrap();
+                assert!(serde_json::from_str::<$curve>(&off_curve).is_err());
+            }
+        )*)
+    }
+
+    curve_serde_tests! {
+        bls12381_serde, Bls12381, Bls12381Base;
+        curve25519_serde, Curve25519, Curve25519Base;
+        pallas_serde, Pallas, PallasBase;
+        vesta_serde, Vesta, PallasScalar;
+    }
+}
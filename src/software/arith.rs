@@ -0,0 +1,238 @@
+//! Multiprecision arithmetic on little-endian `u64` limbs.
+//!
+//! Everything here is `const fn`, so field constants can be computed at compile time.
+
+/// Computes `a + b + carry`, returning the result and the new carry.
+#[inline(always)]
+pub(crate) const fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let tmp = a as u128 + b as u128 + carry as u128;
+    (tmp as u64, (tmp >> 64) as u64)
+}
+
+/// Computes `a - (b + borrow)`, returning the result and the new borrow.
+#[inline(always)]
+pub(crate) const fn sbb(a: u64, b: u64, borrow: u64) -> (u64, u64) {
+    let tmp = (a as u128).wrapping_sub(b as u128 + borrow as u128);
+    (tmp as u64, (tmp >> 127) as u64)
+}
+
+/// Computes `a + b * c + carry`, returning the result and the new carry.
+#[inline(always)]
+pub(crate) const fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let tmp = a as u128 + b as u128 * c as u128 + carry as u128;
+    (tmp as u64, (tmp >> 64) as u64)
+}
+
+/// Returns `true` if all limbs are zero.
+pub(crate) const fn is_zero<const N: usize>(a: &[u64; N]) -> bool {
+    let mut i = 0;
+    while i < N {
+        if a[i] != 0 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns `true` if `a >= b`.
+pub(crate) const fn geq<const N: usize>(a: &[u64; N], b: &[u64; N]) -> bool {
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        if a[i] != b[i] {
+            return a[i] > b[i];
+        }
+    }
+    true
+}
+
+/// Computes `a + b`, returning the result and the carry.
+pub(crate) const fn add<const N: usize>(a: &[u64; N], b: &[u64; N]) -> ([u64; N], u64) {
+    let mut r = [0u64; N];
+    let mut carry = 0;
+    let mut i = 0;
+    while i < N {
+        (r[i], carry) = adc(a[i], b[i], carry);
+        i += 1;
+    }
+    (r, carry)
+}
+
+/// Computes `a - b`, returning the result and the borrow.
+pub(crate) const fn sub<const N: usize>(a: &[u64; N], b: &[u64; N]) -> ([u64; N], u64) {
+    let mut r = [0u64; N];
+    let mut borrow = 0;
+    let mut i = 0;
+    while i < N {
+        (r[i], borrow) = sbb(a[i], b[i], borrow);
+        i += 1;
+    }
+    (r, borrow)
+}
+
+/// Computes `a + b mod m`, assuming `a, b < m`.
+pub(crate) const fn add_mod<const N: usize>(a: &[u64; N], b: &[u64; N], m: &[u64; N]) -> [u64; N] {
+    let (r, carry) = add(a, b);
+    if carry != 0 || geq(&r, m) {
+        sub(&r, m).0
+    } else {
+        r
+    }
+}
+
+/// Computes `a - b mod m`, assuming `a, b < m`.
+pub(crate) const fn sub_mod<const N: usize>(a: &[u64; N], b: &[u64; N], m: &[u64; N]) -> [u64; N] {
+    let (r, borrow) = sub(a, b);
+    if borrow != 0 {
+        add(&r, m).0
+    } else {
+        r
+    }
+}
+
+/// Computes `-a mod m`, assuming `a < m`.
+pub(crate) const fn neg_mod<const N: usize>(a: &[u64; N], m: &[u64; N]) -> [u64; N] {
+    if is_zero(a) {
+        *a
+    } else {
+        sub(m, a).0
+    }
+}
+
+/// Computes `2^bits mod m` by repeated doubling.
+pub(crate) const fn pow2_mod<const N: usize>(bits: usize, m: &[u64; N]) -> [u64; N] {
+    let mut r = [0u64; N];
+    r[0] = 1;
+    let mut i = 0;
+    while i < bits {
+        r = add_mod(&r, &r, m);
+        i += 1;
+    }
+    r
+}
+
+/// Computes `-m^{-1} mod 2^64` for odd `m`.
+pub(crate) const fn mont_inv(m: u64) -> u64 {
+    let mut inv = 1u64;
+    let mut i = 0;
+    while i < 63 {
+        inv = inv.wrapping_mul(inv);
+        inv = inv.wrapping_mul(m);
+        i += 1;
+    }
+    inv.wrapping_neg()
+}
+
+/// Computes `a * b * R^{-1} mod m`, where `R = 2^(64 * N)` (CIOS Montgomery multiplication).
+pub(crate) const fn mont_mul<const N: usize>(
+    a: &[u64; N],
+    b: &[u64; N],
+    m: &[u64; N],
+    inv: u64,
+) -> [u64; N] {
+    let mut t = [0u64; N];
+    let mut t_hi = 0u64;
+    let mut i = 0;
+    while i < N {
+        let mut carry = 0;
+        let mut j = 0;
+        while j < N {
+            (t[j], carry) = mac(t[j], a[j], b[i], carry);
+            j += 1;
+        }
+        let (hi, top) = adc(t_hi, carry, 0);
+
+        let k = t[0].wrapping_mul(inv);
+        let (_, mut carry) = mac(t[0], k, m[0], 0);
+        let mut j = 1;
+        while j < N {
+            (t[j - 1], carry) = mac(t[j], k, m[j], carry);
+            j += 1;
+        }
+        let (lo, c) = adc(hi, carry, 0);
+        t[N - 1] = lo;
+        t_hi = top + c;
+        i += 1;
+    }
+    if t_hi != 0 || geq(&t, m) {
+        sub(&t, m).0
+    } else {
+        t
+    }
+}
+
+/// Returns the number of significant bits of `a`.
+pub(crate) const fn num_bits<const N: usize>(a: &[u64; N]) -> u32 {
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        if a[i] != 0 {
+            return i as u32 * 64 + (64 - a[i].leading_zeros());
+        }
+    }
+    0
+}
+
+/// Returns bit `i` of `a`.
+#[inline(always)]
+pub(crate) const fn bit(a: &[u64], i: usize) -> bool {
+    (a[i / 64] >> (i % 64)) & 1 == 1
+}
+
+/// Computes `a mod b` by binary long division.
+///
+/// # Panics
+///
+/// Panics if `b` is zero.
+pub(crate) fn rem<const N: usize>(a: &[u64; N], b: &[u64; N]) -> [u64; N] {
+    assert!(!is_zero(b), "attempt to calculate the remainder with a divisor of zero");
+    let mut r = [0u64; N];
+    let mut i = num_bits(a) as usize;
+    while i > 0 {
+        i -= 1;
+        let top = r[N - 1] >> 63;
+        let mut j = N - 1;
+        while j > 0 {
+            r[j] = (r[j] << 1) | (r[j - 1] >> 63);
+            j -= 1;
+        }
+        r[0] = (r[0] << 1) | bit(a, i) as u64;
+        if top != 0 || geq(&r, b) {
+            r = sub(&r, b).0;
+        }
+    }
+    r
+}
+
+/// Divides `a` by a small `divisor` in place, returning the remainder.
+pub(crate) fn div_rem_small(a: &mut [u64], divisor: u64) -> u64 {
+    let mut rem = 0u128;
+    for limb in a.iter_mut().rev() {
+        let cur = (rem << 64) | *limb as u128;
+        *limb = (cur / divisor as u128) as u64;
+        rem = cur % divisor as u128;
+    }
+    rem as u64
+}
+
+/// Formats `a` as a decimal number.
+pub(crate) fn to_decimal(a: &[u64]) -> String {
+    const CHUNK: u64 = 10_000_000_000_000_000_000;
+
+    let mut tmp = a.to_vec();
+    let mut chunks = Vec::new();
+    while tmp.iter().any(|&limb| limb != 0) {
+        chunks.push(div_rem_small(&mut tmp, CHUNK));
+    }
+    match chunks.split_last() {
+        None => "0".to_string(),
+        Some((last, rest)) => {
+            let mut s = last.to_string();
+            for chunk in rest.iter().rev() {
+                s.push_str(&format!("{chunk:019}"));
+            }
+            s
+        }
+    }
+}
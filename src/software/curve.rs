@@ -0,0 +1,588 @@
+//! Elliptic curve types.
+//!
+//! Points are stored in affine form so that derived `Eq` and `Ord` are consistent,
+//! while arithmetic is done in projective coordinates.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use super::arith;
+use super::field::{
+    Field, __zkllvm_field_bls12381_base, __zkllvm_field_bls12381_scalar,
+    __zkllvm_field_curve25519_base, __zkllvm_field_curve25519_scalar, __zkllvm_field_pallas_base,
+    __zkllvm_field_pallas_scalar,
+};
+
+/// Point on `y^2 = x^3 + b` in Jacobian coordinates `(X : Y : Z)`,
+/// which represents affine point `(X / Z^2, Y / Z^3)`.
+#[derive(Clone, Copy)]
+struct Jacobian<F> {
+    x: F,
+    y: F,
+    z: F,
+}
+
+impl<F: Field> Jacobian<F> {
+    const IDENTITY: Self = Self { x: F::ONE, y: F::ONE, z: F::ZERO };
+
+    fn from_affine(p: Option<(F, F)>) -> Self {
+        match p {
+            Some((x, y)) => Self { x, y, z: F::ONE },
+            None => Self::IDENTITY,
+        }
+    }
+
+    fn to_affine(self) -> Option<(F, F)> {
+        let z_inv = self.z.inverse()?;
+        let z_inv2 = z_inv.square();
+        Some((self.x * z_inv2, self.y * z_inv2 * z_inv))
+    }
+
+    /// `dbl-2009-l` formulas.
+    fn double(&self) -> Self {
+        let a = self.x.square();
+        let b = self.y.square();
+        let c = b.square();
+        let d = (self.x + b).square() - a - c;
+        let d = d + d;
+        let e = a + a + a;
+        let f = e.square();
+        let x = f - d - d;
+        let c8 = c + c;
+        let c8 = c8 + c8;
+        let c8 = c8 + c8;
+        let y = e * (d - x) - c8;
+        let z = self.y * self.z;
+        Self { x, y, z: z + z }
+    }
+
+    /// `add-2007-bl` formulas with exceptional cases handled.
+    fn add(&self, other: &Self) -> Self {
+        if self.z.is_zero() {
+            return *other;
+        }
+        if other.z.is_zero() {
+            return *self;
+        }
+        let z1z1 = self.z.square();
+        let z2z2 = other.z.square();
+        let u1 = self.x * z2z2;
+        let u2 = other.x * z1z1;
+        let s1 = self.y * other.z * z2z2;
+        let s2 = other.y * self.z * z1z1;
+        let h = u2 - u1;
+        let r = s2 - s1;
+        if h.is_zero() {
+            return if r.is_zero() { self.double() } else { Self::IDENTITY };
+        }
+        let i = (h + h).square();
+        let j = h * i;
+        let r = r + r;
+        let v = u1 * i;
+        let x = r.square() - j - v - v;
+        let s1j = s1 * j;
+        let y = r * (v - x) - s1j - s1j;
+        let z = ((self.z + other.z).square() - z1z1 - z2z2) * h;
+        Self { x, y, z }
+    }
+
+    fn mul(&self, scalar: &[u64]) -> Self {
+        let mut res = Self::IDENTITY;
+        for i in (0..scalar.len() * 64).rev() {
+            res = res.double();
+            if arith::bit(scalar, i) {
+                res = res.add(self);
+            }
+        }
+        res
+    }
+}
+
+/// Point on `a * x^2 + y^2 = 1 + d * x^2 * y^2` in projective coordinates `(X : Y : Z)`,
+/// which represents affine point `(X / Z, Y / Z)`.
+#[derive(Clone, Copy)]
+struct Projective<F> {
+    x: F,
+    y: F,
+    z: F,
+}
+
+impl<F: Field> Projective<F> {
+    const IDENTITY: Self = Self { x: F::ZERO, y: F::ONE, z: F::ONE };
+
+    fn from_affine((x, y): (F, F)) -> Self {
+        Self { x, y, z: F::ONE }
+    }
+
+    fn to_affine(self) -> (F, F) {
+        let z_inv = self.z.inverse().expect("complete formulas never produce zero Z");
+        (self.x * z_inv, self.y * z_inv)
+    }
+
+    /// `add-2007-bl` formulas, which are complete for square `a` and non-square `d`.
+    fn add(&self, other: &Self, a: F, d: F) -> Self {
+        let aa = self.z * other.z;
+        let b = aa.square();
+        let c = self.x * other.x;
+        let dd = self.y * other.y;
+        let e = d * c * dd;
+        let f = b - e;
+        let g = b + e;
+        let x = aa * f * ((self.x + self.y) * (other.x + other.y) - c - dd);
+        let y = aa * g * (dd - a * c);
+        Self { x, y, z: f * g }
+    }
+
+    fn mul(&self, scalar: &[u64], a: F, d: F) -> Self {
+        let mut res = Self::IDENTITY;
+        for i in (0..scalar.len() * 64).rev() {
+            res = res.add(&res, a, d);
+            if arith::bit(scalar, i) {
+                res = res.add(self, a, d);
+            }
+        }
+        res
+    }
+}
+
+/// Implements scalar multiplication and formatting shared by all curve types.
+macro_rules! curve_common_impl {
+    ($name:ident, $scalar:ident) => {
+        impl Mul<$scalar> for $name {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, other: $scalar) -> Self {
+                self.mul_limbs(&other.to_le_limbs())
+            }
+        }
+
+        impl Mul<$name> for $scalar {
+            type Output = $name;
+
+            #[inline]
+            fn mul(self, other: $name) -> $name {
+                other * self
+            }
+        }
+
+        impl Div<$scalar> for $name {
+            type Output = Self;
+
+            /// # Panics
+            ///
+            /// Panics if `other` is zero.
+            #[inline]
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn div(self, other: $scalar) -> Self {
+                self * other.inverse().expect("attempt to divide by zero")
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, other: Self) -> Self {
+                self + -other
+            }
+        }
+
+        impl AddAssign for $name {
+            #[inline]
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $name {
+            #[inline]
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl MulAssign<$scalar> for $name {
+            #[inline]
+            fn mul_assign(&mut self, other: $scalar) {
+                *self = *self * other;
+            }
+        }
+
+        impl DivAssign<$scalar> for $name {
+            #[inline]
+            fn div_assign(&mut self, other: $scalar) {
+                *self = *self / other;
+            }
+        }
+
+        impl Default for $name {
+            #[inline]
+            fn default() -> Self {
+                Self::zero()
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self, f)
+            }
+        }
+    };
+}
+
+/// Defines a curve type in short Weierstrass form `y^2 = x^3 + b`.
+macro_rules! weierstrass_curve_type {
+    ($($name:ident, $base:ident, $scalar:ident, $b:expr, $gx:expr, $gy:expr;)*) => ($(
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Copy, Eq, Hash, PartialEq, PartialOrd, Ord)]
+        pub struct $name {
+            x: $base,
+            y: $base,
+            infinity: bool,
+        }
+
+        impl $name {
+            /// Curve coefficient `b`.
+            pub const B: $base = $base::from_le_limbs_unwrap($b);
+
+            const GENERATOR: Self = Self::from_coordinates(
+                $base::from_le_limbs_unwrap($gx),
+                $base::from_le_limbs_unwrap($gy),
+            );
+
+            /// Create curve element from affine coordinates without any checks.
+            #[inline]
+            pub const fn from_coordinates(x: $base, y: $base) -> Self {
+                Self { x, y, infinity: false }
+            }
+
+            /// Create neutral curve element.
+            #[inline]
+            pub const fn zero() -> Self {
+                Self { x: $base::ZERO, y: $base::ZERO, infinity: true }
+            }
+
+            /// Create generator curve element.
+            #[inline]
+            pub const fn one() -> Self {
+                Self::GENERATOR
+            }
+
+            /// Affine `x` coordinate, zero for the point at infinity.
+            #[inline]
+            pub const fn x(&self) -> $base {
+                self.x
+            }
+
+            /// Affine `y` coordinate, zero for the point at infinity.
+            #[inline]
+            pub const fn y(&self) -> $base {
+                self.y
+            }
+
+            fn to_jacobian(self) -> Jacobian<$base> {
+                Jacobian::from_affine((!self.infinity).then_some((self.x, self.y)))
+            }
+
+            fn from_jacobian(p: Jacobian<$base>) -> Self {
+                match p.to_affine() {
+                    Some((x, y)) => Self::from_coordinates(x, y),
+                    None => Self::zero(),
+                }
+            }
+
+            fn mul_limbs(self, scalar: &[u64]) -> Self {
+                Self::from_jacobian(self.to_jacobian().mul(scalar))
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, other: Self) -> Self {
+                Self::from_jacobian(self.to_jacobian().add(&other.to_jacobian()))
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                if self.infinity {
+                    self
+                } else {
+                    Self::from_coordinates(self.x, -self.y)
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                if self.infinity {
+                    f.write_str("infinity")
+                } else {
+                    write!(f, "({}, {})", self.x, self.y)
+                }
+            }
+        }
+
+        curve_common_impl!($name, $scalar);
+    )*)
+}
+
+/// Defines a curve type in twisted Edwards form `a * x^2 + y^2 = 1 + d * x^2 * y^2`.
+macro_rules! edwards_curve_type {
+    ($($name:ident, $base:ident, $scalar:ident, $a:expr, $d:expr, $gx:expr, $gy:expr;)*) => ($(
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Copy, Eq, Hash, PartialEq, PartialOrd, Ord)]
+        pub struct $name {
+            x: $base,
+            y: $base,
+        }
+
+        impl $name {
+            /// Curve coefficient `a`.
+            pub const A: $base = $base::from_le_limbs_unwrap($a);
+
+            /// Curve coefficient `d`.
+            pub const D: $base = $base::from_le_limbs_unwrap($d);
+
+            const GENERATOR: Self = Self::from_coordinates(
+                $base::from_le_limbs_unwrap($gx),
+                $base::from_le_limbs_unwrap($gy),
+            );
+
+            /// Create curve element from affine coordinates without any checks.
+            #[inline]
+            pub const fn from_coordinates(x: $base, y: $base) -> Self {
+                Self { x, y }
+            }
+
+            /// Create neutral curve element.
+            #[inline]
+            pub const fn zero() -> Self {
+                Self::from_coordinates($base::ZERO, $base::ONE)
+            }
+
+            /// Create generator curve element.
+            #[inline]
+            pub const fn one() -> Self {
+                Self::GENERATOR
+            }
+
+            /// Affine `x` coordinate.
+            #[inline]
+            pub const fn x(&self) -> $base {
+                self.x
+            }
+
+            /// Affine `y` coordinate.
+            #[inline]
+            pub const fn y(&self) -> $base {
+                self.y
+            }
+
+            fn mul_limbs(self, scalar: &[u64]) -> Self {
+                let p = Projective::from_affine((self.x, self.y)).mul(scalar, Self::A, Self::D);
+                let (x, y) = p.to_affine();
+                Self::from_coordinates(x, y)
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, other: Self) -> Self {
+                let p = Projective::from_affine((self.x, self.y));
+                let q = Projective::from_affine((other.x, other.y));
+                let (x, y) = p.add(&q, Self::A, Self::D).to_affine();
+                Self::from_coordinates(x, y)
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self::from_coordinates(-self.x, self.y)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "({}, {})", self.x, self.y)
+            }
+        }
+
+        curve_common_impl!($name, $scalar);
+    )*)
+}
+
+weierstrass_curve_type! {
+    __zkllvm_curve_bls12381, __zkllvm_field_bls12381_base, __zkllvm_field_bls12381_scalar,
+    [4, 0, 0, 0, 0, 0],
+    [
+        0xfb3af00adb22c6bb, 0x6c55e83ff97a1aef, 0xa14e3a3f171bac58,
+        0xc3688c4f9774b905, 0x2695638c4fa9ac0f, 0x17f1d3a73197d794,
+    ],
+    [
+        0x0caa232946c5e7e1, 0xd03cc744a2888ae4, 0x00db18cb2c04b3ed,
+        0xfcf5e095d5d00af6, 0xa09e30ed741d8ae4, 0x08b3f481e3aaa0f1,
+    ];
+
+    __zkllvm_curve_pallas, __zkllvm_field_pallas_base, __zkllvm_field_pallas_scalar,
+    [5, 0, 0, 0],
+    crate::PallasBase::MODULUS_MINUS_ONE,
+    [2, 0, 0, 0];
+
+    __zkllvm_curve_vesta, __zkllvm_field_pallas_scalar, __zkllvm_field_pallas_base,
+    [5, 0, 0, 0],
+    crate::PallasScalar::MODULUS_MINUS_ONE,
+    [2, 0, 0, 0];
+}
+
+edwards_curve_type! {
+    __zkllvm_curve_curve25519, __zkllvm_field_curve25519_base, __zkllvm_field_curve25519_scalar,
+    crate::Curve25519Base::MODULUS_MINUS_ONE,
+    [0x75eb4dca135978a3, 0x00700a4d4141d8ab, 0x8cc740797779e898, 0x52036cee2b6ffe73],
+    [0xc9562d608f25d51a, 0x692cc7609525a7b2, 0xc0a4e231fdd6dc5c, 0x216936d3cd6e53fe],
+    [0x6666666666666658, 0x6666666666666666, 0x6666666666666666, 0x6666666666666666];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Generates group law tests for a curve type.
+    ///
+    /// Multiples of the generator are computed independently with affine formulas.
+    macro_rules! curve_tests {
+        ($($mod:ident, $t:ident, $base:ident, $scalar:ident, $on_curve:expr,
+           g2: ($g2x:expr, $g2y:expr), g3: ($g3x:expr, $g3y:expr);)*) => ($(
+            mod $mod {
+                use super::*;
+
+                fn point(x: [u64; <$base>::MODULUS.len()], y: [u64; <$base>::MODULUS.len()]) -> $t {
+                    let p = $t::from_coordinates(
+                        $base::from_le_limbs(x).unwrap(),
+                        $base::from_le_limbs(y).unwrap(),
+                    );
+                    assert!($on_curve(p.x(), p.y()));
+                    p
+                }
+
+                fn scalar(n: u64) -> $scalar {
+                    let mut limbs = [0; <$scalar>::MODULUS.len()];
+                    limbs[0] = n;
+                    $scalar::from_le_limbs(limbs).unwrap()
+                }
+
+                #[test]
+                fn generator_is_on_curve() {
+                    let g = $t::one();
+                    assert!($on_curve(g.x(), g.y()));
+                }
+
+                #[test]
+                fn addition() {
+                    let g = $t::one();
+                    let g2 = point($g2x, $g2y);
+                    let g3 = point($g3x, $g3y);
+                    assert_eq!(g + g, g2);
+                    assert_eq!(g2 + g, g3);
+                    assert_eq!(g + g2, g3);
+                    assert_eq!(g3 - g, g2);
+                    assert_eq!((g + g2) + g3, g + (g2 + g3));
+                    assert_eq!(g + $t::zero(), g);
+                    assert_eq!($t::zero() + g, g);
+                    assert_eq!(g - g, $t::zero());
+                    assert_eq!(-$t::zero(), $t::zero());
+                    assert_eq!(g2 + -g, g);
+                }
+
+                #[test]
+                fn scalar_multiplication() {
+                    let g = $t::one();
+                    let g3 = point($g3x, $g3y);
+                    assert_eq!(g * scalar(0), $t::zero());
+                    assert_eq!(g * scalar(1), g);
+                    assert_eq!(g * scalar(3), g3);
+                    assert_eq!(scalar(3) * g, g3);
+                    assert_eq!(g * -scalar(1), -g);
+                    assert_eq!(g3 / scalar(3), g);
+                    assert_eq!(g.mul_limbs(&$scalar::MODULUS), $t::zero());
+                }
+            }
+        )*)
+    }
+
+    curve_tests! {
+        bls12381, __zkllvm_curve_bls12381,
+        __zkllvm_field_bls12381_base, __zkllvm_field_bls12381_scalar,
+        |x, y| y * y == x * x * x + __zkllvm_curve_bls12381::B,
+        g2: (
+            [
+                0xc39a8c5529bf0f4e, 0xe28f75bb8f1c7c42, 0x43902d0ac358a62a,
+                0x9721db3091280125, 0x8808c8eb50a9450c, 0x0572cbea904d6746,
+            ],
+            [
+                0xba86881979749d28, 0x4c56d9d4cd16bd1b, 0xf73bb9021d5fd76a,
+                0x22ba3ecb8670e461, 0x22fda673779d8e38, 0x166a9d8cabc673a3,
+            ]
+        ),
+        g3: (
+            [
+                0x96d2c0c9024e5224, 0x81747a0b2ca2179b, 0xf3780a51335b3ff9,
+                0xb112d61f9be9a5f1, 0x1765212deca99697, 0x09ece308f9d1f013,
+            ],
+            [
+                0xa3473b0590ae30d1, 0xe745256c634af45c, 0x9d9c27310fd43be6,
+                0xa69a0cddabc3097f, 0x8a84623389c5f80c, 0x032b80d3a6f5b09f,
+            ]
+        );
+
+        pallas, __zkllvm_curve_pallas,
+        __zkllvm_field_pallas_base, __zkllvm_field_pallas_scalar,
+        |x, y| y * y == x * x * x + __zkllvm_curve_pallas::B,
+        g2: (
+            [0x1303c567b0000003, 0x0efee2ee4411acfc, 0x0000000000000000, 0x1c00000000000000],
+            [0x8aea5cdf3bfffffc, 0x17076ec9563fb75e, 0x0000000000000000, 0x2b00000000000000]
+        ),
+        g3: (
+            [0xb7f08a3beb32d263, 0xfff647de5af5fc5c, 0xb84c45a7474edf4c, 0x08e7566fbaa967ed],
+            [0x187ffb7b17eb87c5, 0xc7c89e18de142df1, 0x3897d34a1f5ef0cb, 0x301d0a4cc182e0f4]
+        );
+
+        vesta, __zkllvm_curve_vesta,
+        __zkllvm_field_pallas_scalar, __zkllvm_field_pallas_base,
+        |x, y| y * y == x * x * x + __zkllvm_curve_vesta::B,
+        g2: (
+            [0xed5f06de70000003, 0x0efee2ee443109e0, 0x0000000000000000, 0x1c00000000000000],
+            [0xda3fa5fa2bfffffc, 0x17076ec9566fe174, 0x0000000000000000, 0x2b00000000000000]
+        ),
+        g3: (
+            [0x5aee6feb6f55ce5f, 0x2402e1bada0d5615, 0xc6f41a28d0a526b0, 0x377879a8395c9513],
+            [0xe7bd83fc4ae53086, 0x7b45d94acebc2a59, 0x713baebef7086247, 0x2006adf2119a4d16]
+        );
+
+        curve25519, __zkllvm_curve_curve25519,
+        __zkllvm_field_curve25519_base, __zkllvm_field_curve25519_scalar,
+        |x, y| {
+            let (a, d) = (__zkllvm_curve_curve25519::A, __zkllvm_curve_curve25519::D);
+            a * x * x + y * y == __zkllvm_field_curve25519_base::ONE + d * x * x * y * y
+        },
+        g2: (
+            [0x83c5a14e2843ce0e, 0x080d8e4515d7a45f, 0x3d043b7d1833e7ac, 0x36ab384c9f5a046c],
+            [0x0e5f46ae6af8a3c9, 0x97390f5164385156, 0x1da25ee8c9a21f56, 0x2260cdf3092329c2]
+        ),
+        g3: (
+            [0xac62485fd3f8e25c, 0x6343981981624886, 0x1ff4ae743edac83a, 0x67ae9c4a22928f49],
+            [0x02c3684878f5b4d4, 0x9f16ec1767240304, 0xa126a18e60269ef7, 0x1267b1d177ee69ab]
+        );
+    }
+}
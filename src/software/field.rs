@@ -0,0 +1,419 @@
+//! Prime field types.
+//!
+//! Elements are kept in Montgomery form, so the derived `Eq` compares canonical values.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+use super::arith;
+
+/// Field operations used by generic curve arithmetic.
+pub(crate) trait Field:
+    Copy + Eq + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn is_zero(&self) -> bool;
+
+    fn square(&self) -> Self;
+
+    fn inverse(&self) -> Option<Self>;
+}
+
+/// Defines a prime field type with the given number of limbs and modulus.
+macro_rules! field_type {
+    ($($name:ident, $limbs:literal, $modulus:expr;)*) => ($(
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Copy, Eq, PartialEq)]
+        pub struct $name([u64; $limbs]);
+
+        impl $name {
+            /// Field modulus as little-endian limbs.
+            pub const MODULUS: [u64; $limbs] = $modulus;
+
+            /// `-MODULUS^{-1} mod 2^64`.
+            const INV: u64 = arith::mont_inv(Self::MODULUS[0]);
+
+            /// `R mod MODULUS`, where `R = 2^(64 * limbs)`.
+            const R: [u64; $limbs] = arith::pow2_mod(64 * $limbs, &Self::MODULUS);
+
+            /// `R^2 mod MODULUS`.
+            const R2: [u64; $limbs] = arith::pow2_mod(128 * $limbs, &Self::MODULUS);
+
+            /// `MODULUS - 2`, the exponent of inversion.
+            const MODULUS_MINUS_TWO: [u64; $limbs] = {
+                let mut two = [0; $limbs];
+                two[0] = 2;
+                arith::sub(&Self::MODULUS, &two).0
+            };
+
+            /// Additive identity.
+            pub const ZERO: Self = Self([0; $limbs]);
+
+            /// Multiplicative identity.
+            pub const ONE: Self = Self(Self::R);
+
+            /// Create field element from canonical little-endian limbs.
+            ///
+            /// Returns `None` if the value is not less than the modulus.
+            pub const fn from_le_limbs(limbs: [u64; $limbs]) -> Option<Self> {
+                if arith::geq(&limbs, &Self::MODULUS) {
+                    None
+                } else {
+                    Some(Self(arith::mont_mul(&limbs, &Self::R2, &Self::MODULUS, Self::INV)))
+                }
+            }
+
+            /// Create field element from canonical little-endian limbs in constant context.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the value is not less than the modulus.
+            pub const fn from_le_limbs_unwrap(limbs: [u64; $limbs]) -> Self {
+                match Self::from_le_limbs(limbs) {
+                    Some(value) => value,
+                    None => panic!("value is not less than the modulus"),
+                }
+            }
+
+            /// Canonical little-endian limbs of the field element.
+            pub const fn to_le_limbs(&self) -> [u64; $limbs] {
+                let mut one = [0; $limbs];
+                one[0] = 1;
+                arith::mont_mul(&self.0, &one, &Self::MODULUS, Self::INV)
+            }
+
+            /// Returns `true` if the element is zero.
+            pub const fn is_zero(&self) -> bool {
+                arith::is_zero(&self.0)
+            }
+
+            /// Computes `self * self`.
+            pub fn square(&self) -> Self {
+                *self * *self
+            }
+
+            /// Computes `self^exp`, where `exp` is given as little-endian limbs.
+            pub fn pow(&self, exp: &[u64]) -> Self {
+                let mut res = Self::ONE;
+                for i in (0..exp.len() * 64).rev() {
+                    res = res.square();
+                    if arith::bit(exp, i) {
+                        res *= *self;
+                    }
+                }
+                res
+            }
+
+            /// Computes the multiplicative inverse, if `self` is nonzero.
+            pub fn inverse(&self) -> Option<Self> {
+                if self.is_zero() {
+                    None
+                } else {
+                    Some(self.pow(&Self::MODULUS_MINUS_TWO))
+                }
+            }
+        }
+
+        impl Field for $name {
+            const ZERO: Self = Self::ZERO;
+            const ONE: Self = Self::ONE;
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                $name::is_zero(self)
+            }
+
+            #[inline]
+            fn square(&self) -> Self {
+                $name::square(self)
+            }
+
+            #[inline]
+            fn inverse(&self) -> Option<Self> {
+                $name::inverse(self)
+            }
+        }
+
+        impl Default for $name {
+            #[inline]
+            fn default() -> Self {
+                Self::ZERO
+            }
+        }
+
+        impl PartialOrd for $name {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        /// Elements are ordered by their canonical integer values.
+        impl Ord for $name {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.to_le_limbs().iter().rev().cmp(other.to_le_limbs().iter().rev())
+            }
+        }
+
+        /// Hashes canonical value, consistent with `Eq`.
+        impl Hash for $name {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.to_le_limbs().hash(state);
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self, f)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.pad_integral(true, "", &arith::to_decimal(&self.to_le_limbs()))
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, other: Self) -> Self {
+                Self(arith::add_mod(&self.0, &other.0, &Self::MODULUS))
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, other: Self) -> Self {
+                Self(arith::sub_mod(&self.0, &other.0, &Self::MODULUS))
+            }
+        }
+
+        impl Mul for $name {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, other: Self) -> Self {
+                Self(arith::mont_mul(&self.0, &other.0, &Self::MODULUS, Self::INV))
+            }
+        }
+
+        impl Div for $name {
+            type Output = Self;
+
+            /// # Panics
+            ///
+            /// Panics if `other` is zero.
+            #[inline]
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn div(self, other: Self) -> Self {
+                self * other.inverse().expect("attempt to divide by zero")
+            }
+        }
+
+        /// Remainder of the canonical integer values.
+        impl Rem for $name {
+            type Output = Self;
+
+            /// # Panics
+            ///
+            /// Panics if `other` is zero.
+            #[inline]
+            fn rem(self, other: Self) -> Self {
+                let r = arith::rem(&self.to_le_limbs(), &other.to_le_limbs());
+                Self::from_le_limbs(r).unwrap()
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self(arith::neg_mod(&self.0, &Self::MODULUS))
+            }
+        }
+
+        impl AddAssign for $name {
+            #[inline]
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $name {
+            #[inline]
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl MulAssign for $name {
+            #[inline]
+            fn mul_assign(&mut self, other: Self) {
+                *self = *self * other;
+            }
+        }
+
+        impl DivAssign for $name {
+            #[inline]
+            fn div_assign(&mut self, other: Self) {
+                *self = *self / other;
+            }
+        }
+
+        impl RemAssign for $name {
+            #[inline]
+            fn rem_assign(&mut self, other: Self) {
+                *self = *self % other;
+            }
+        }
+    )*)
+}
+
+field_type! {
+    __zkllvm_field_bls12381_base, 6, crate::Bls12381Base::MODULUS;
+    __zkllvm_field_bls12381_scalar, 4, crate::Bls12381Scalar::MODULUS;
+    __zkllvm_field_curve25519_base, 4, crate::Curve25519Base::MODULUS;
+    __zkllvm_field_curve25519_scalar, 4, crate::Curve25519Scalar::MODULUS;
+    __zkllvm_field_pallas_base, 4, crate::PallasBase::MODULUS;
+    __zkllvm_field_pallas_scalar, 4, crate::PallasScalar::MODULUS;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Generates arithmetic tests for a field type.
+    ///
+    /// `a = (p - 1) / 3` and `b = 0xdeadbeef * 2^130 + 12345`,
+    /// expected values are computed independently with arbitrary-precision integers.
+    macro_rules! field_tests {
+        ($($mod:ident, $t:ident,
+           a: $a:expr, b: $b:expr, sum: $sum:expr, diff: $diff:expr, prod: $prod:expr,
+           quot: $quot:expr;)*) => ($(
+            mod $mod {
+                use super::*;
+
+                fn a() -> $t {
+                    $t::from_le_limbs($a).unwrap()
+                }
+
+                fn b() -> $t {
+                    $t::from_le_limbs($b).unwrap()
+                }
+
+                #[test]
+                fn limbs_round_trip() {
+                    assert_eq!(a().to_le_limbs(), $a);
+                    assert!($t::from_le_limbs($t::MODULUS).is_none());
+                    let max = arith::sub(&$t::MODULUS, &$t::ONE.to_le_limbs()).0;
+                    assert_eq!($t::from_le_limbs(max).unwrap().to_le_limbs(), max);
+                }
+
+                #[test]
+                fn arithmetic() {
+                    assert_eq!((a() + b()).to_le_limbs(), $sum);
+                    assert_eq!((b() - a()).to_le_limbs(), $diff);
+                    assert_eq!((a() * b()).to_le_limbs(), $prod);
+                    assert_eq!((a() / b()).to_le_limbs(), $quot);
+                    assert_eq!(a() + -a(), $t::ZERO);
+                    assert_eq!(-$t::ZERO, $t::ZERO);
+                    // `3 * a = p - 1 = -1`.
+                    assert_eq!(a() + a() + a(), -$t::ONE);
+                }
+
+                #[test]
+                fn inverse() {
+                    assert_eq!($t::ZERO.inverse(), None);
+                    assert_eq!($t::ONE.inverse(), Some($t::ONE));
+                    assert_eq!(a() * a().inverse().unwrap(), $t::ONE);
+                    assert_eq!(b() * b().inverse().unwrap(), $t::ONE);
+                }
+
+                #[test]
+                fn ordering_and_formatting() {
+                    assert!(b() < a());
+                    assert!(-$t::ONE > a());
+                    assert_eq!($t::ZERO.to_string(), "0");
+                    assert_eq!(b().to_string(), "5085082450816203602937596985679026020187285499961");
+                    assert_eq!(a() % b(), $t::from_le_limbs(arith::rem(&$a, &$b)).unwrap());
+                }
+            }
+        )*)
+    }
+
+    field_tests! {
+        bls12381_base, __zkllvm_field_bls12381_base,
+        a: [
+            0x9354ffffffffe38e, 0x0a395554e5c6aaaa, 0xcd104635a790520c,
+            0xcc27c3d6fbd7063f, 0x190937e76bc3e447, 0x08ab05f8bdd54cde,
+        ],
+        b: [0x3039, 0x0, 0x37ab6fbbc, 0x0, 0x0, 0x0],
+        sum: [
+            0x93550000000013c7, 0x0a395554e5c6aaaa, 0xcd10463922474dc8,
+            0xcc27c3d6fbd7063f, 0x190937e76bc3e447, 0x08ab05f8bdd54cde,
+        ],
+        diff: [
+            0x26a9fffffffff756, 0x1472aaa9cb8d5555, 0x9a208c6ec9d79fd4,
+            0x984f87adf7ae0c7f, 0x32126fced787c88f, 0x11560bf17baa99bc,
+        ],
+        prod: [
+            0x7bff555555550c5f, 0x69c7ffff20e2aaaa, 0x9a208c6a2638fad9,
+            0x984f87adf7ae0c7f, 0x32126fced787c88f, 0x11560bf17baa99bc,
+        ],
+        quot: [
+            0xb537d886511344c6, 0x4eb44c0c5cbbd382, 0xe422d7e0db095606,
+            0xcdf550900c857e68, 0x158886b667de4639, 0x194d553ae1187dbd,
+        ];
+
+        bls12381_scalar, __zkllvm_field_bls12381_scalar,
+        a: [0x5555555500000000, 0x713f36abaaaa1eaa, 0x66689d580335f2ac, 0x26a48d1bb889d46d],
+        b: [0x3039, 0x0, 0x37ab6fbbc, 0x0],
+        sum: [0x5555555500003039, 0x713f36abaaaa1eaa, 0x66689d5b7decee68, 0x26a48d1bb889d46d],
+        diff: [0xaaaaaaaa0000303a, 0xe27e6d5755543d54, 0xccd13ab38122e114, 0x4d491a377113a8da],
+        prod: [0xffffffff55554543, 0x37d3c2acaaa992a9, 0xccd13aaedd843c1a, 0x4d491a377113a8da],
+        quot: [0x0a3d6d40e3002aae, 0x301b886599df5826, 0x8c554020343b0879, 0x56c420ff3c04dcb3];
+
+        curve25519_base, __zkllvm_field_curve25519_base,
+        a: [0xaaaaaaaaaaaaaaa4, 0xaaaaaaaaaaaaaaaa, 0xaaaaaaaaaaaaaaaa, 0x2aaaaaaaaaaaaaaa],
+        b: [0x3039, 0x0, 0x37ab6fbbc, 0x0],
+        sum: [0xaaaaaaaaaaaadadd, 0xaaaaaaaaaaaaaaaa, 0xaaaaaaae2561a666, 0x2aaaaaaaaaaaaaaa],
+        diff: [0x5555555555558582, 0x5555555555555555, 0x55555558d00c5111, 0x5555555555555555],
+        prod: [0xaaaaaaaaaaaa9a8b, 0xaaaaaaaaaaaaaaaa, 0x555555542c6dac16, 0x5555555555555555],
+        quot: [0x72f31a717b9e847e, 0xc10914ec5c8260a6, 0x376bdea248d5ece9, 0x09798ebe9bf988b6];
+
+        curve25519_scalar, __zkllvm_field_curve25519_scalar,
+        a: [0xc8062108c9a746a4, 0x5c4a534a3652899c, 0x5555555555555555, 0x0555555555555555],
+        b: [0x3039, 0x0, 0x37ab6fbbc, 0x0],
+        sum: [0xc8062108c9a776dd, 0x5c4a534a3652899c, 0x55555558d00c5111, 0x0555555555555555],
+        diff: [0x900c4211934ebd82, 0xb894a6946ca51339, 0xaaaaaaae2561a666, 0x0aaaaaaaaaaaaaaa],
+        prod: [0xe5619766e8a3d28b, 0x0de9fbe9c1fa688e, 0xaaaaaaa981c3016c, 0x0aaaaaaaaaaaaaaa],
+        quot: [0xd34e7250ec34ce9f, 0x0fb526c49ba55479, 0x8a599f4991b4bae8, 0x093ac492d7d861c3];
+
+        pallas_base, __zkllvm_field_pallas_base,
+        a: [0x330f104f00000000, 0x60c232feadc45309, 0x5555555555555555, 0x1555555555555555],
+        b: [0x3039, 0x0, 0x37ab6fbbc, 0x0],
+        sum: [0x330f104f00003039, 0x60c232feadc45309, 0x55555558d00c5111, 0x1555555555555555],
+        diff: [0x661e209e0000303a, 0xc18465fd5b88a612, 0xaaaaaaae2561a666, 0x2aaaaaaaaaaaaaaa],
+        prod: [0xbb7375f355554543, 0x16d9bb52b0ddfb67, 0xaaaaaaa981c3016c, 0x2aaaaaaaaaaaaaaa],
+        quot: [0x382684de58512273, 0x9a92bf0f214dd036, 0x17568a75d6648f69, 0x394f41468eb85666];
+
+        pallas_scalar, __zkllvm_field_pallas_scalar,
+        a: [0xd96cf90b00000000, 0x60c232feaddc3849, 0x5555555555555555, 0x1555555555555555],
+        b: [0x3039, 0x0, 0x37ab6fbbc, 0x0],
+        sum: [0xd96cf90b00003039, 0x60c232feaddc3849, 0x55555558d00c5111, 0x1555555555555555],
+        diff: [0xb2d9f2160000303a, 0xc18465fd5bb87093, 0xaaaaaaae2561a666, 0x2aaaaaaaaaaaaaaa],
+        prod: [0x082f476b55554543, 0x16d9bb52b10dc5e9, 0xaaaaaaa981c3016c, 0x2aaaaaaaaaaaaaaa],
+        quot: [0x18b5b0166e0103eb, 0x863441cf4651e463, 0x0ce86db6718d7f02, 0x0341134bbd8448c6];
+    }
+}
@@ -0,0 +1,21 @@
+//! Pure-Rust implementation of zkLLVM builtin types.
+//!
+//! Enabled by `software` feature. Types here have the same names and semantics as
+//! `rslang` builtins, so type wrappers can be built and tested with the original Rust compiler
+//! on any host.
+//!
+//! There are no field literals (e.g. `1g`) outside of `rslang`,
+//! so values are constructed with `from_le_limbs` instead.
+
+mod arith;
+mod curve;
+mod field;
+
+pub use curve::{
+    __zkllvm_curve_bls12381, __zkllvm_curve_curve25519, __zkllvm_curve_pallas,
+    __zkllvm_curve_vesta,
+};
+pub use field::{
+    __zkllvm_field_bls12381_base, __zkllvm_field_bls12381_scalar, __zkllvm_field_curve25519_base,
+    __zkllvm_field_curve25519_scalar, __zkllvm_field_pallas_base, __zkllvm_field_pallas_scalar,
+};
@@ -0,0 +1,178 @@
+//! Square roots and Legendre symbol.
+//!
+//! Square roots are computed with Tonelli-Shanks algorithm, which needs a quadratic
+//! non-residue raised to the power of `t`, where `p - 1 = 2^s * t` with odd `t`.
+//! Multiplicative generators of all fields are non-residues, so this is
+//! [`ZkField::ROOT_OF_UNITY`], other constants are derived from the modulus.
+
+use super::{
+    Bls12381Base, Bls12381Scalar, Curve25519Base, Curve25519Scalar, PallasBase, PallasScalar,
+    ZkField,
+};
+
+/// Indicates whether a field element is a square.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum LegendreSymbol {
+    /// The element is zero.
+    Zero,
+    /// The element is a nonzero square.
+    QuadraticResidue,
+    /// The element is not a square.
+    QuadraticNonResidue,
+}
+
+/// Implements `pow`, `legendre` and `sqrt`.
+macro_rules! sqrt_impl {
+    ($($t:ident)*) => ($(
+        impl $t {
+            /// Computes `self^exp`, where `exp` is given as little-endian limbs.
+            pub fn pow(&self, exp: &[u64]) -> Self {
+                let mut res = Self(builtin_one!($t));
+                for i in (0..64 * exp.len()).rev() {
+                    res *= res;
+                    if (exp[i / 64] >> (i % 64)) & 1 == 1 {
+                        res *= *self;
+                    }
+                }
+                res
+            }
+
+            /// Computes Legendre symbol of the element using Euler's criterion.
+            pub fn legendre(&self) -> LegendreSymbol {
+                let s = self.pow(&Self::MODULUS_MINUS_ONE_DIV_TWO);
+                if s == Self(builtin_zero!($t)) {
+                    LegendreSymbol::Zero
+                } else if s == Self(builtin_one!($t)) {
+                    LegendreSymbol::QuadraticResidue
+                } else {
+                    LegendreSymbol::QuadraticNonResidue
+                }
+            }
+
+            /// Returns a square root of the element, if it exists.
+            ///
+            /// Which of the two roots is returned is unspecified.
+            pub fn sqrt(&self) -> Option<Self> {
+                let one = Self(builtin_one!($t));
+                if *self == Self(builtin_zero!($t)) {
+                    return Some(*self);
+                }
+
+                // `w = self^((t - 1) / 2)`, `x = self^((t + 1) / 2)`, `b = self^t`.
+                let w = self.pow(&Self::TRACE_MINUS_ONE_DIV_TWO);
+                let mut x = *self * w;
+                let mut b = x * w;
+                let mut c = <Self as ZkField>::ROOT_OF_UNITY;
+                let mut m = Self::TWO_ADICITY;
+
+                while b != one {
+                    // Find the least `i` such that `b^(2^i) = 1`.
+                    let mut i = 0;
+                    let mut b2i = b;
+                    while b2i != one {
+                        b2i *= b2i;
+                        i += 1;
+                        if i == m {
+                            return None;
+                        }
+                    }
+
+                    let mut g = c;
+                    for _ in 0..m - i - 1 {
+                        g *= g;
+                    }
+                    x *= g;
+                    c = g * g;
+                    b *= c;
+                    m = i;
+                }
+                Some(x)
+            }
+        }
+    )*)
+}
+
+sqrt_impl! {
+    Bls12381Base
+    Bls12381Scalar
+    Curve25519Base
+    Curve25519Scalar
+    PallasBase
+    PallasScalar
+}
+
+#[cfg(all(test, feature = "software"))]
+mod tests {
+    use super::*;
+
+    /// Generates tests of `sqrt` and `legendre`.
+    macro_rules! sqrt_tests {
+        ($($name:ident, $t:ident;)*) => ($(
+            #[test]
+            fn $name() {
+                let zero = $t(builtin_zero!($t));
+                let one = $t(builtin_one!($t));
+                let g = <$t as ZkField>::MULTIPLICATIVE_GENERATOR;
+
+                // Tonelli-Shanks relies on the generator being a non-residue.
+                assert_eq!(g.legendre(), LegendreSymbol::QuadraticNonResidue);
+                assert_eq!(g.sqrt(), None);
+
+                assert_eq!(zero.legendre(), LegendreSymbol::Zero);
+                assert_eq!(zero.sqrt(), Some(zero));
+                assert_eq!(one.legendre(), LegendreSymbol::QuadraticResidue);
+                assert!(matches!(one.sqrt(), Some(root) if root == one || root == -one));
+
+                let mut x = g + one;
+                for _ in 0..16 {
+                    let square = x * x;
+                    assert_eq!(square.legendre(), LegendreSymbol::QuadraticResidue);
+                    let root = square.sqrt().unwrap();
+                    assert!(root == x || root == -x);
+
+                    let non_residue = square * g;
+                    assert_eq!(non_residue.legendre(), LegendreSymbol::QuadraticNonResidue);
+                    assert_eq!(non_residue.sqrt(), None);
+
+                    x = square + one;
+                }
+            }
+        )*)
+    }
+
+    sqrt_tests! {
+        bls12381_base_sqrt, Bls12381Base;
+        bls12381_scalar_sqrt, Bls12381Scalar;
+        curve25519_base_sqrt, Curve25519Base;
+        curve25519_scalar_sqrt, Curve25519Scalar;
+        pallas_base_sqrt, PallasBase;
+        pallas_scalar_sqrt, PallasScalar;
+    }
+
+    #[test]
+    fn minus_one() {
+        // `p = 3 mod 4`, so `-1` is not a square.
+        let one = Bls12381Base(builtin_one!(Bls12381Base));
+        assert_eq!((-one).legendre(), LegendreSymbol::QuadraticNonResidue);
+        assert_eq!((-one).sqrt(), None);
+
+        // `p = 5 mod 8` with `s = 2`, square roots of `-1` are the roots of unity of order 4.
+        let one = Curve25519Base(builtin_one!(Curve25519Base));
+        let i = <Curve25519Base as ZkField>::ROOT_OF_UNITY;
+        assert_eq!(i * i, -one);
+        assert_eq!((-one).legendre(), LegendreSymbol::QuadraticResidue);
+        assert!(matches!((-one).sqrt(), Some(root) if root == i || root == -i));
+    }
+
+    #[test]
+    fn pow() {
+        let x = PallasBase::from_le_limbs([3, 0, 0, 0]).unwrap();
+        assert_eq!(x.pow(&[]), PallasBase(builtin_one!(PallasBase)));
+        assert_eq!(x.pow(&[0, 0]), PallasBase(builtin_one!(PallasBase)));
+        assert_eq!(x.pow(&[5]), x * x * x * x * x);
+        assert_eq!(
+            x.pow(&PallasBase::MODULUS_MINUS_ONE),
+            PallasBase(builtin_one!(PallasBase))
+        );
+    }
+}
@@ -0,0 +1,276 @@
+//! Validating constructors of curve elements.
+
+use std::error::Error;
+use std::fmt;
+
+use super::{
+    Bls12381, Bls12381Base, Bls12381Scalar, Curve25519, Curve25519Base, Curve25519Scalar, Pallas,
+    PallasBase, PallasScalar, Vesta, VestaBase,
+};
+
+/// Error returned when coordinates do not define a valid curve element.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum CurveError {
+    /// Coordinates do not satisfy the curve equation.
+    NotOnCurve,
+    /// The point is on the curve, but not in its prime-order subgroup.
+    NotInSubgroup,
+    /// Encoding of the point is malformed.
+    InvalidEncoding,
+}
+
+impl fmt::Display for CurveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CurveError::NotOnCurve => f.write_str("point is not on the curve"),
+            CurveError::NotInSubgroup => f.write_str("point is not in the prime-order subgroup"),
+            CurveError::InvalidEncoding => f.write_str("invalid point encoding"),
+        }
+    }
+}
+
+impl Error for CurveError {}
+
+/// Implements curve equation check for curves in short Weierstrass form `y^2 = x^3 + b`.
+macro_rules! weierstrass_check_impl {
+    ($($curve:ty, $base:ident;)*) => ($(
+        impl $curve {
+            /// Returns `true` if affine coordinates satisfy the curve equation.
+            pub(crate) fn is_on_curve(x: $base, y: $base) -> bool {
+                y * y == x * x * x + Self::COEFF_B
+            }
+        }
+    )*)
+}
+
+weierstrass_check_impl! {
+    Bls12381, Bls12381Base;
+    Pallas, PallasBase;
+    Vesta, PallasScalar;
+}
+
+/// Implements curve equation check for curves in twisted Edwards form
+/// `a * x^2 + y^2 = 1 + d * x^2 * y^2`.
+macro_rules! edwards_check_impl {
+    ($($curve:ty, $base:ident;)*) => ($(
+        impl $curve {
+            /// Returns `true` if affine coordinates satisfy the curve equation.
+            pub(crate) fn is_on_curve(x: $base, y: $base) -> bool {
+                let x2 = x * x;
+                let y2 = y * y;
+                Self::COEFF_A * x2 + y2 == $base(builtin_one!($base)) + Self::COEFF_D * x2 * y2
+            }
+        }
+    )*)
+}
+
+edwards_check_impl! {
+    Curve25519, Curve25519Base;
+}
+
+/// Implements prime-order subgroup check.
+macro_rules! subgroup_check_impl {
+    (prime_order: $($curve:ty)*) => ($(
+        impl $curve {
+            /// Returns `true`, since the whole curve group has prime order.
+            #[inline(always)]
+            pub(crate) fn is_torsion_free(&self) -> bool {
+                true
+            }
+        }
+    )*);
+    (cofactor: $($curve:ty, $scalar:ident)*) => ($(
+        impl $curve {
+            /// Returns `true` if the point is in the prime-order subgroup,
+            /// i.e. `r * P` is neutral for the subgroup order `r`.
+            ///
+            /// `r` itself is zero in the scalar field, so `(r - 1) * P = -P` is checked instead.
+            pub(crate) fn is_torsion_free(&self) -> bool {
+                let minus_one = -$scalar(builtin_one!($scalar));
+                *self * minus_one == -*self
+            }
+        }
+    )*);
+}
+
+subgroup_check_impl! {
+    prime_order:
+    Pallas
+    Vesta
+}
+
+subgroup_check_impl! {
+    cofactor:
+    Bls12381, Bls12381Scalar
+    Curve25519, Curve25519Scalar
+}
+
+/// Implements `try_from_coordinates`.
+macro_rules! try_from_coordinates_impl {
+    ($($curve:ty, $base:ty)*) => ($(
+        impl $curve {
+            /// Create curve element from base field coordinates,
+            /// checking that they define a point of the prime-order group.
+            ///
+            /// Neutral element of a short Weierstrass curve has no affine coordinates,
+            /// so it cannot be created this way.
+            pub fn try_from_coordinates(x: $base, y: $base) -> Result<Self, CurveError> {
+                if !Self::is_on_curve(x, y) {
+                    return Err(CurveError::NotOnCurve);
+                }
+                // SAFETY: the curve equation is checked above.
+                let point = unsafe { Self::from_coordinates(x, y) };
+                if !point.is_torsion_free() {
+                    return Err(CurveError::NotInSubgroup);
+                }
+                Ok(point)
+            }
+        }
+    )*)
+}
+
+try_from_coordinates_impl! {
+    Bls12381, Bls12381Base
+    Curve25519, Curve25519Base
+    Pallas, PallasBase
+    Vesta, VestaBase
+}
+
+/// Implements `try_from_affine`, accepting coordinates of the neutral element
+/// as they are returned by `x` and `y` accessors.
+///
+/// Used by encodings of curve elements with coordinates.
+#[cfg(any(feature = "serde", all(feature = "assigner", feature = "software")))]
+macro_rules! try_from_affine_impl {
+    (weierstrass: $($curve:ty, $base:ident)*) => ($(
+        impl $curve {
+            /// Create curve element from affine coordinates, checking that they define a point
+            /// of the prime-order group, or that both are zero for the neutral element.
+            pub(crate) fn try_from_affine(x: $base, y: $base) -> Result<Self, CurveError> {
+                let zero = $base(builtin_zero!($base));
+                if x == zero && y == zero {
+                    return Ok(Self::zero());
+                }
+                Self::try_from_coordinates(x, y)
+            }
+        }
+    )*);
+    (edwards: $($curve:ty, $base:ident)*) => ($(
+        impl $curve {
+            /// Create curve element from affine coordinates, checking that they define a point
+            /// of the prime-order group.
+            ///
+            /// Neutral element `(0, 1)` is an affine point, so it needs no special handling.
+            #[inline(always)]
+            pub(crate) fn try_from_affine(x: $base, y: $base) -> Result<Self, CurveError> {
+                Self::try_from_coordinates(x, y)
+            }
+        }
+    )*);
+}
+
+#[cfg(any(feature = "serde", all(feature = "assigner", feature = "software")))]
+try_from_affine_impl! {
+    weierstrass:
+    Bls12381, Bls12381Base
+    Pallas, PallasBase
+    Vesta, PallasScalar
+}
+
+#[cfg(any(feature = "serde", all(feature = "assigner", feature = "software")))]
+try_from_affine_impl! {
+    edwards:
+    Curve25519, Curve25519Base
+}
+
+#[cfg(all(test, feature = "software"))]
+mod tests {
+    use super::*;
+
+    /// Generates tests of `try_from_coordinates` with multiples of the generator.
+    macro_rules! try_from_coordinates_tests {
+        ($($name:ident, $curve:ty, $base:ident;)*) => ($(
+            #[test]
+            fn $name() {
+                let one = $base(builtin_one!($base));
+                let g = <$curve>::one();
+                let mut p = g;
+                for _ in 0..8 {
+                    let (x, y) = ($base(p.0.x()), $base(p.0.y()));
+                    assert_eq!(<$curve>::try_from_coordinates(x, y), Ok(p));
+                    let (nx, ny) = ($base((-p).0.x()), $base((-p).0.y()));
+                    assert_eq!(<$curve>::try_from_coordinates(nx, ny), Ok(-p));
+                    assert_eq!(
+                        <$curve>::try_from_coordinates(x, y + one),
+                        Err(CurveError::NotOnCurve),
+                    );
+                    assert_eq!(
+                        <$curve>::try_from_coordinates(x + one, y),
+                        Err(CurveError::NotOnCurve),
+                    );
+                    p += g;
+                }
+            }
+        )*)
+    }
+
+    try_from_coordinates_tests! {
+        bls12381_try_from_coordinates, Bls12381, Bls12381Base;
+        curve25519_try_from_coordinates, Curve25519, Curve25519Base;
+        pallas_try_from_coordinates, Pallas, PallasBase;
+        vesta_try_from_coordinates, Vesta, PallasScalar;
+    }
+
+    #[test]
+    fn neutral_element() {
+        // Short Weierstrass curves have no affine neutral element, `(0, 0)` is not on them.
+        let zero = PallasBase(builtin_zero!(PallasBase));
+        assert_eq!(
+            Pallas::try_from_coordinates(zero, zero),
+            Err(CurveError::NotOnCurve)
+        );
+        let zero = Bls12381Base(builtin_zero!(Bls12381Base));
+        assert_eq!(
+            Bls12381::try_from_coordinates(zero, zero),
+            Err(CurveError::NotOnCurve)
+        );
+
+        // `(0, 1)` is the neutral element of twisted Edwards curves.
+        let zero = Curve25519Base(builtin_zero!(Curve25519Base));
+        let one = Curve25519Base(builtin_one!(Curve25519Base));
+        assert_eq!(
+            Curve25519::try_from_coordinates(zero, one),
+            Ok(Curve25519::zero())
+        );
+    }
+
+    #[test]
+    fn not_in_subgroup() {
+        // `(0, 2)` satisfies `y^2 = x^3 + 4` and has order 3, which does not divide `r`.
+        let zero = Bls12381Base(builtin_zero!(Bls12381Base));
+        let one = Bls12381Base(builtin_one!(Bls12381Base));
+        assert_eq!(
+            Bls12381::try_from_coordinates(zero, one + one),
+            Err(CurveError::NotInSubgroup),
+        );
+
+        // `(0, -1)` is the point of order 2 on any twisted Edwards curve.
+        let zero = Curve25519Base(builtin_zero!(Curve25519Base));
+        let minus_one = -Curve25519Base(builtin_one!(Curve25519Base));
+        assert_eq!(
+            Curve25519::try_from_coordinates(zero, minus_one),
+            Err(CurveError::NotInSubgroup),
+        );
+    }
+
+    #[cfg(any(feature = "serde", feature = "assigner"))]
+    #[test]
+    fn try_from_affine_neutral_element() {
+        let zero = PallasBase(builtin_zero!(PallasBase));
+        assert_eq!(Pallas::try_from_affine(zero, zero), Ok(Pallas::zero()));
+        let zero = PallasScalar(builtin_zero!(PallasScalar));
+        assert_eq!(Vesta::try_from_affine(zero, zero), Ok(Vesta::zero()));
+        let zero = Bls12381Base(builtin_zero!(Bls12381Base));
+        assert_eq!(Bls12381::try_from_affine(zero, zero), Ok(Bls12381::zero()));
+    }
+}
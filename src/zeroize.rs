@@ -14,7 +14,7 @@ macro_rules! zeroize_impl {
     ($($t:ty)*) => ($(
         impl Zeroize for $t {
             fn zeroize(&mut self) {
-                self.0 = 0g;
+                self.0 = builtin_zero!($t);
             }
         }
     )*)